@@ -42,6 +42,98 @@ pub enum P2PExchangeError {
     DisputeExpired,
     #[msg("Invalid currency code format")]
     InvalidCurrencyCode,
+    #[msg("Order book page is full")]
+    OrderBookPageFull,
+    #[msg("Order book page does not match the offer")]
+    InvalidOrderBookPage,
+    #[msg("Offer already uses the current layout")]
+    OfferAlreadyMigrated,
+    #[msg("Batch accounts are malformed")]
+    InvalidBatchAccounts,
+    #[msg("Too many offers in batch")]
+    BatchTooLarge,
+    #[msg("Offer terms no longer match the expected terms")]
+    OfferTermsChanged,
+    #[msg("Juror registry is full")]
+    JurorRegistryFull,
+    #[msg("Juror is not eligible to serve")]
+    JurorNotEligible,
+    #[msg("Juror still has active disputes")]
+    JurorHasActiveDisputes,
+    #[msg("Juror seat has already been settled")]
+    JurorAlreadySettled,
+    #[msg("Juror draw slot has not been reached yet")]
+    DrawSlotNotReached,
+    #[msg("Not enough eligible jurors in the registry")]
+    NotEnoughEligibleJurors,
+    #[msg("Juror stake account missing or out of order")]
+    InvalidJurorStakeAccount,
+    #[msg("Vote reveal phase has not started")]
+    RevealNotOpen,
+    #[msg("Revealed vote does not match the commitment")]
+    InvalidVoteReveal,
+    #[msg("Invalid juror panel configuration")]
+    InvalidPanelConfig,
+    #[msg("Dispute phase lengths must be positive")]
+    InvalidDisputeTimings,
+    #[msg("Number of jurors does not match the panel size")]
+    InvalidPanelSize,
+    #[msg("Verdict can still be appealed")]
+    AppealWindowOpen,
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("Verdict cannot be appealed")]
+    AppealNotAvailable,
+    #[msg("Buyer allocation must be between 0 and 100 percent")]
+    InvalidAllocation,
+    #[msg("Reveal phase is still open")]
+    RevealStillOpen,
+    #[msg("Dispute has not expired yet")]
+    DisputeNotExpired,
+    #[msg("Evidence phase has ended")]
+    EvidencePhaseClosed,
+    #[msg("Voting phase is not open")]
+    VotingNotOpen,
+    #[msg("Phase extension was already requested")]
+    ExtensionAlreadyRequested,
+    #[msg("No pending phase extension")]
+    NoPendingExtension,
+    #[msg("Juror already approved the extension")]
+    ExtensionAlreadyApproved,
+    #[msg("Respondent has not paid the dispute fee")]
+    RespondentFeeUnpaid,
+    #[msg("Dispute fee was already paid")]
+    DisputeFeeAlreadyPaid,
+    #[msg("Respondent can still pay the dispute fee")]
+    ResponseWindowOpen,
+    #[msg("Respondent fee deadline has passed")]
+    ResponseWindowClosed,
+    #[msg("Juror accounts do not match the panel")]
+    InvalidJurorAccounts,
+    #[msg("No pending settlement proposal")]
+    NoPendingSettlement,
+    #[msg("Settlement proposal no longer matches the expected terms")]
+    SettlementTermsChanged,
+    #[msg("Juror appears more than once on the panel")]
+    DuplicateJuror,
+    #[msg("Juror has a conflict of interest in this dispute")]
+    JurorConflictOfInterest,
+    #[msg("Juror is still within the inactivity window")]
+    JurorNotInactive,
+    #[msg("No juror seat is awaiting a replacement")]
+    NoVacantSeats,
+    #[msg("Too many juror seats were vacated in this dispute")]
+    VacancyLimitReached,
+    #[msg("A justification hash is required")]
+    MissingJustification,
+    #[msg("Override timelock has not elapsed")]
+    OverrideTimelockActive,
+    #[msg("Override has already been executed")]
+    OverrideAlreadyExecuted,
+    #[msg("Cooldowns cannot be negative")]
+    InvalidCooldown,
+    #[msg("Reputation already uses the current layout")]
+    ReputationAlreadyMigrated,
 }
 
 // Maintain backward compatibility
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::Admin;
+use crate::state::{Admin, CooldownConfig, DisputeConfig, DisputeTimings, PanelTier, Reputation, MAX_PANEL_SIZE, MAX_PANEL_TIERS, MIN_PANEL_SIZE};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -30,6 +30,82 @@ pub struct UpdateAdminAuthorities<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeDisputeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DisputeConfig::LEN,
+        seeds = [DisputeConfig::SEED.as_bytes()],
+        bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDisputeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [DisputeConfig::SEED.as_bytes()],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCooldownConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CooldownConfig::LEN,
+        seeds = [CooldownConfig::SEED.as_bytes()],
+        bump
+    )]
+    pub cooldown_config: Account<'info, CooldownConfig>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCooldownConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CooldownConfig::SEED.as_bytes()],
+        bump = cooldown_config.bump
+    )]
+    pub cooldown_config: Account<'info, CooldownConfig>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    pub authority: Signer<'info>,
+}
+
 pub fn initialize_admin(ctx: Context<InitializeAdmin>) -> Result<()> {
     let admin = &mut ctx.accounts.admin;
     let authority = &ctx.accounts.authority;
@@ -60,6 +136,113 @@ pub fn update_admin_authorities(
     Ok(())
 }
 
+pub fn initialize_dispute_config(
+    ctx: Context<InitializeDisputeConfig>,
+    panel_tiers: Vec<PanelTier>,
+    timings: DisputeTimings,
+) -> Result<()> {
+    validate_panel_tiers(&panel_tiers)?;
+    validate_dispute_timings(&timings)?;
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.authority = ctx.accounts.authority.key();
+    dispute_config.panel_tiers = panel_tiers;
+    dispute_config.timings = timings;
+    dispute_config.bump = ctx.bumps.dispute_config;
+
+    Ok(())
+}
+
+pub fn update_panel_tiers(
+    ctx: Context<UpdateDisputeConfig>,
+    panel_tiers: Vec<PanelTier>,
+) -> Result<()> {
+    validate_panel_tiers(&panel_tiers)?;
+
+    ctx.accounts.dispute_config.panel_tiers = panel_tiers;
+
+    Ok(())
+}
+
+pub fn update_dispute_timings(
+    ctx: Context<UpdateDisputeConfig>,
+    timings: DisputeTimings,
+) -> Result<()> {
+    validate_dispute_timings(&timings)?;
+
+    // Disputes already open keep the timings they started with
+    ctx.accounts.dispute_config.timings = timings;
+
+    Ok(())
+}
+
+pub fn initialize_cooldown_config(ctx: Context<InitializeCooldownConfig>) -> Result<()> {
+    let cooldown_config = &mut ctx.accounts.cooldown_config;
+    cooldown_config.authority = ctx.accounts.authority.key();
+    cooldown_config.offer_creation_cooldown = Reputation::OFFER_CREATION_COOLDOWN;
+    cooldown_config.dispute_opening_cooldown = Reputation::DISPUTE_OPENING_COOLDOWN;
+    cooldown_config.bump = ctx.bumps.cooldown_config;
+
+    Ok(())
+}
+
+pub fn update_cooldowns(
+    ctx: Context<UpdateCooldownConfig>,
+    offer_creation_cooldown: i64,
+    dispute_opening_cooldown: i64,
+) -> Result<()> {
+    // A zero cooldown turns the rate limit off
+    if offer_creation_cooldown < 0 || dispute_opening_cooldown < 0 {
+        return Err(error!(ErrorCode::InvalidCooldown));
+    }
+
+    let cooldown_config = &mut ctx.accounts.cooldown_config;
+    cooldown_config.offer_creation_cooldown = offer_creation_cooldown;
+    cooldown_config.dispute_opening_cooldown = dispute_opening_cooldown;
+
+    Ok(())
+}
+
+// Helper function to validate panel tiers: odd sizes, ascending amounts, first tier at 0
+fn validate_panel_tiers(panel_tiers: &[PanelTier]) -> Result<()> {
+    if panel_tiers.is_empty() || panel_tiers.len() > MAX_PANEL_TIERS {
+        return Err(error!(ErrorCode::InvalidPanelConfig));
+    }
+    if panel_tiers[0].min_amount != 0 {
+        return Err(error!(ErrorCode::InvalidPanelConfig));
+    }
+
+    for (index, tier) in panel_tiers.iter().enumerate() {
+        if tier.panel_size < MIN_PANEL_SIZE
+            || tier.panel_size > MAX_PANEL_SIZE
+            || tier.panel_size % 2 == 0
+        {
+            return Err(error!(ErrorCode::InvalidPanelConfig));
+        }
+        if index > 0 && tier.min_amount <= panel_tiers[index - 1].min_amount {
+            return Err(error!(ErrorCode::InvalidPanelConfig));
+        }
+    }
+
+    Ok(())
+}
+
+// Helper function to validate dispute timings: every phase must have a length
+fn validate_dispute_timings(timings: &DisputeTimings) -> Result<()> {
+    if timings.evidence_period <= 0
+        || timings.voting_period <= 0
+        || timings.reveal_period <= 0
+        || timings.appeal_window <= 0
+        || timings.response_period <= 0
+        || timings.inactivity_window <= 0
+        || timings.override_timelock <= 0
+    {
+        return Err(error!(ErrorCode::InvalidDisputeTimings));
+    }
+
+    Ok(())
+}
+
 // Helper function to validate multi-sig authorization
 pub fn validate_admin_authority(admin: &Admin, signers: &[Pubkey]) -> Result<()> {
     let mut valid_signatures = 0;
@@ -81,4 +264,32 @@ pub fn validate_admin_authority(admin: &Admin, signers: &[Pubkey]) -> Result<()>
     } else {
         Err(error!(ErrorCode::AdminRequired))
     }
-}
\ No newline at end of file
+}
+
+// Helper function to collect the admin authorities that signed an instruction
+// (the authority account plus any signing remaining accounts) and check the threshold
+pub(crate) fn collect_admin_signers(
+    admin: &Admin,
+    authority: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Vec<Pubkey>> {
+    let mut signers = vec![*authority];
+    signers.extend(
+        remaining_accounts
+            .iter()
+            .filter(|account| account.is_signer)
+            .map(|account| account.key()),
+    );
+
+    validate_admin_authority(admin, &signers)?;
+
+    // Record each recognized authority once
+    let mut admin_signers = Vec::new();
+    for key in std::iter::once(&admin.authority).chain(admin.secondary_authorities.iter()) {
+        if *key != Pubkey::default() && signers.contains(key) && !admin_signers.contains(key) {
+            admin_signers.push(*key);
+        }
+    }
+
+    Ok(admin_signers)
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use crate::state::{EscrowAccount, Offer, OrderBookPage, BatchOfferAction, BatchOfferResult, OffersBatchProcessed, MAX_BATCH_OFFERS};
+use crate::errors::ErrorCode;
+use crate::instructions::offers::{list_offer_in_order_book, delist_offer_from_order_book, cancel_offer_and_refund};
+
+/// Batch operations over many offers owned by the same seller
+///
+/// Offers are passed through `remaining_accounts`:
+/// * list / delist - `[offer, order_book_page]` pairs
+/// * cancel - `[offer, escrow_account, order_book_page]` triples, where the
+///   program ID stands in for the page of an offer that is not listed
+#[derive(Accounts)]
+pub struct BatchOffers<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn batch_list_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+    process_batch(&ctx, BatchOfferAction::List, 2)
+}
+
+pub fn batch_delist_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+    process_batch(&ctx, BatchOfferAction::Delist, 2)
+}
+
+pub fn batch_cancel_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+    process_batch(&ctx, BatchOfferAction::Cancel, 3)
+}
+
+fn process_batch<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, BatchOffers<'info>>,
+    action: BatchOfferAction,
+    accounts_per_offer: usize,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let clock = Clock::get()?;
+
+    // Validate batch shape
+    if remaining_accounts.is_empty() || !remaining_accounts.len().is_multiple_of(accounts_per_offer) {
+        return Err(error!(ErrorCode::InvalidBatchAccounts));
+    }
+    if remaining_accounts.len() / accounts_per_offer > MAX_BATCH_OFFERS {
+        return Err(error!(ErrorCode::BatchTooLarge));
+    }
+
+    let seller = ctx.accounts.seller.to_account_info();
+
+    // Each offer succeeds or fails on its own; failures are reported, not propagated
+    let mut results = Vec::with_capacity(remaining_accounts.len() / accounts_per_offer);
+    for accounts in remaining_accounts.chunks(accounts_per_offer) {
+        let outcome = match action {
+            BatchOfferAction::List => {
+                process_list(&seller, &accounts[0], &accounts[1], clock.unix_timestamp)
+            }
+            BatchOfferAction::Delist => {
+                process_delist(&seller, &accounts[0], &accounts[1], clock.unix_timestamp)
+            }
+            BatchOfferAction::Cancel => {
+                process_cancel(&seller, &accounts[0], &accounts[1], &accounts[2], clock.unix_timestamp)
+            }
+        };
+
+        results.push(BatchOfferResult {
+            offer: accounts[0].key(),
+            success: outcome.is_ok(),
+            error_code: outcome.err().map(|err| error_code_number(&err)).unwrap_or(0),
+        });
+    }
+
+    // Emit event
+    emit!(OffersBatchProcessed {
+        seller: seller.key(),
+        action: action as u8,
+        results,
+    });
+
+    Ok(())
+}
+
+fn process_list<'info>(
+    seller: &AccountInfo<'info>,
+    offer_info: &'info AccountInfo<'info>,
+    page_info: &'info AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    let mut offer = load_seller_offer(seller, offer_info)?;
+    let mut order_book_page = load_mut::<OrderBookPage>(page_info)?;
+
+    list_offer_in_order_book(offer.key(), &mut offer, &mut order_book_page, now)?;
+
+    offer.exit(&crate::ID)?;
+    order_book_page.exit(&crate::ID)?;
+    Ok(())
+}
+
+fn process_delist<'info>(
+    seller: &AccountInfo<'info>,
+    offer_info: &'info AccountInfo<'info>,
+    page_info: &'info AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    let mut offer = load_seller_offer(seller, offer_info)?;
+    let mut order_book_page = load_mut::<OrderBookPage>(page_info)?;
+
+    delist_offer_from_order_book(offer.key(), &mut offer, &mut order_book_page, now)?;
+
+    offer.exit(&crate::ID)?;
+    order_book_page.exit(&crate::ID)?;
+    Ok(())
+}
+
+fn process_cancel<'info>(
+    seller: &AccountInfo<'info>,
+    offer_info: &'info AccountInfo<'info>,
+    escrow_info: &'info AccountInfo<'info>,
+    page_info: &'info AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    let mut offer = load_seller_offer(seller, offer_info)?;
+
+    // Escrow must belong to this offer; only the escrow PDA is initialized with its key
+    let escrow_account = load_mut::<EscrowAccount>(escrow_info)?;
+    if escrow_account.offer != offer.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // The program ID is used as a placeholder for offers that are not listed
+    let mut order_book_page = if page_info.key() == crate::ID {
+        None
+    } else {
+        Some(load_mut::<OrderBookPage>(page_info)?)
+    };
+
+    cancel_offer_and_refund(
+        offer.key(),
+        &mut offer,
+        &escrow_account,
+        order_book_page.as_deref_mut(),
+        seller,
+        now,
+    )?;
+
+    offer.exit(&crate::ID)?;
+    if let Some(order_book_page) = order_book_page {
+        order_book_page.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+// Helper function to load an offer and validate seller ownership
+fn load_seller_offer<'info>(
+    seller: &AccountInfo<'info>,
+    offer_info: &'info AccountInfo<'info>,
+) -> Result<Account<'info, Offer>> {
+    let offer = load_mut::<Offer>(offer_info)?;
+    if offer.seller != seller.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+    Ok(offer)
+}
+
+// Helper function to deserialize a writable program account from remaining accounts
+fn load_mut<'info, T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+    info: &'info AccountInfo<'info>,
+) -> Result<Account<'info, T>> {
+    if !info.is_writable {
+        return Err(error!(anchor_lang::error::ErrorCode::ConstraintMut));
+    }
+    Account::try_from(info)
+}
+
+// Helper function to extract the numeric error code reported in batch results.
+// Builtin program errors live in the upper 32 bits, so they report their index instead,
+// which stays below Anchor's error code range
+fn error_code_number(err: &Error) -> u32 {
+    match err {
+        Error::AnchorError(anchor_error) => anchor_error.error_code_number,
+        Error::ProgramError(program_error) => match program_error.program_error {
+            ProgramError::Custom(code) => code,
+            ref builtin => (u64::from(builtin.clone()) >> 32) as u32,
+        },
+    }
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
-use crate::state::{Admin, EscrowAccount, Offer, Dispute, Vote, Reputation, OfferStatus, DisputeStatus, MAX_DISPUTE_REASON_LEN, MAX_EVIDENCE_URL_LEN, MAX_EVIDENCE_ITEMS};
-use crate::state::{DisputeOpened, JurorsAssigned, EvidenceSubmitted, VoteCast, VerdictExecuted, RewardEligible};
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::system_program;
+use std::cmp::Ordering;
+use crate::state::{Admin, EscrowAccount, Offer, Dispute, DisputeConfig, CooldownConfig, DisputeEscrow, OverrideRecord, RoundVerdict, DisputeRole, Evidence, Vote, JurorRegistry, JurorStake, TradeHistory, RewardToken, UserRewards, Reputation, OfferStatus, DisputeStatus, MAX_DISPUTE_REASON_LEN, MAX_EVIDENCE_URL_LEN, MAX_EVIDENCE_MIME_TYPE_LEN, MAX_APPEAL_ROUNDS, MAX_PANEL_SIZE};
+use crate::state::{DisputeOpened, JurorsAssigned, EvidenceSubmitted, PhaseExtensionRequested, PhaseExtensionApproved, PhasesExtended, VoteCommitted, VoteRevealed, DisputeAppealed, AppealBondSettled, VerdictExecuted, ExpiredDisputeResolved, DisputeFeePaid, DefaultJudgment, SettlementProposed, SettlementAccepted, DisputeFeesSettled, ExpiredDisputePolicy, JurorCompensated, JurorFeesForfeited, RewardsEarned, ForceResolveProposed, ForceResolveCancelled, DisputeForceResolved};
 use crate::errors::ErrorCode;
-use crate::utils::validate_and_process_string;
+use crate::utils::{transfer_from_escrow, transfer_from_pda, validate_and_process_string};
+use crate::instructions::admin::collect_admin_signers;
+use crate::instructions::reputation::{record_trade_outcome, TradeOutcome};
+use crate::instructions::jurors::{conflicted_jurors, reserve_juror};
 
 // Remove the duplicated validate_and_trim_string function - now using common utility
 
@@ -13,23 +18,70 @@ pub struct OpenDispute<'info> {
     pub dispute: Account<'info, Dispute>,
     #[account(mut)]
     pub offer: Account<'info, Offer>,
+    #[account(
+        init,
+        payer = initiator,
+        space = 8 + DisputeEscrow::LEN,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
     #[account(mut)]
     pub initiator: Signer<'info>,
     /// CHECK: This is the respondent in the dispute
     pub respondent: AccountInfo<'info>,
+    #[account(
+        seeds = [DisputeConfig::SEED.as_bytes()],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut, seeds = [b"reputation", initiator.key().as_ref()], bump)]
+    pub reputation: Account<'info, Reputation>,
+    #[account(
+        seeds = [CooldownConfig::SEED.as_bytes()],
+        bump = cooldown_config.bump
+    )]
+    pub cooldown_config: Account<'info, CooldownConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayDisputeFee<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    #[account(mut)]
+    pub respondent: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+/// Remaining accounts: the `JurorStake` of each juror, one per panel seat
 #[derive(Accounts)]
 pub struct AssignJurors<'info> {
     #[account(mut)]
     pub dispute: Account<'info, Dispute>,
-    /// CHECK: This is juror 1
-    pub juror1: AccountInfo<'info>,
-    /// CHECK: This is juror 2
-    pub juror2: AccountInfo<'info>,
-    /// CHECK: This is juror 3
-    pub juror3: AccountInfo<'info>,
+    #[account(
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    /// CHECK: Trade history of the initiator; uninitialized if the user never created one
+    #[account(
+        seeds = [TradeHistory::SEED.as_bytes(), dispute.initiator.as_ref()],
+        bump
+    )]
+    pub initiator_trade_history: UncheckedAccount<'info>,
+    /// CHECK: Trade history of the respondent; uninitialized if the user never created one
+    #[account(
+        seeds = [TradeHistory::SEED.as_bytes(), dispute.respondent.as_ref()],
+        bump
+    )]
+    pub respondent_trade_history: UncheckedAccount<'info>,
     #[account(
         seeds = [Admin::SEED.as_bytes()],
         bump,
@@ -44,13 +96,37 @@ pub struct AssignJurors<'info> {
 pub struct SubmitEvidence<'info> {
     #[account(mut)]
     pub dispute: Account<'info, Dispute>,
+    #[account(constraint = offer.key() == dispute.offer @ ErrorCode::Unauthorized)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + Evidence::LEN,
+        seeds = [Evidence::SEED.as_bytes(), dispute.key().as_ref(), &dispute.evidence_count.to_le_bytes()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
     #[account(mut)]
     pub submitter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestPhaseExtension<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    pub requester: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApprovePhaseExtension<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    pub juror: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(vote_for_buyer: bool)]
-pub struct CastVote<'info> {
+pub struct CommitVote<'info> {
     #[account(mut)]
     pub dispute: Account<'info, Dispute>,
     #[account(mut)]
@@ -66,6 +142,80 @@ pub struct CastVote<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    pub juror: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vote", dispute.key().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+#[derive(Accounts)]
+pub struct AppealVerdict<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(constraint = offer.key() == dispute.offer @ ErrorCode::Unauthorized)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeSettlement<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(constraint = offer.key() == dispute.offer @ ErrorCode::Unauthorized)]
+    pub offer: Account<'info, Offer>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSettlement<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    /// CHECK: This is the buyer
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    /// CHECK: This is the seller
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    pub accepter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: `[juror, vote, user_rewards]` for every seat of the current panel
 #[derive(Accounts)]
 pub struct ExecuteVerdict<'info> {
     #[account(mut)]
@@ -78,20 +228,194 @@ pub struct ExecuteVerdict<'info> {
         bump = escrow_account.bump
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
+    /// Holds the dispute fees and any appeal bond
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    /// CHECK: This is the buyer
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    /// CHECK: This is the seller
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+    /// Receives the juror fees no majority juror is owed
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    /// CHECK: Rate for crediting juror vote rewards; uninitialized if the reward system is not set up
+    #[account(seeds = [RewardToken::SEED.as_bytes()], bump)]
+    pub reward_token: UncheckedAccount<'info>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    /// Anyone can crank execution once the verdict is final
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveExpiredDispute<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    /// Holds the dispute fees and any appeal bond
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    /// CHECK: This is the buyer
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    /// CHECK: This is the seller
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDefaultJudgment<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
     /// CHECK: This is the buyer
     #[account(mut)]
     pub buyer: AccountInfo<'info>,
     /// CHECK: This is the seller
     #[account(mut)]
     pub seller: AccountInfo<'info>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: signing secondary admin authorities
+#[derive(Accounts)]
+pub struct ProposeForceResolve<'info> {
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OverrideRecord::LEN,
+        seeds = [OverrideRecord::SEED.as_bytes(), dispute.key().as_ref()],
+        bump
+    )]
+    pub override_record: Account<'info, OverrideRecord>,
     #[account(
         seeds = [Admin::SEED.as_bytes()],
-        bump,
-        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+        bump
+    )]
+    pub admin: Account<'info, Admin>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: signing secondary admin authorities
+#[derive(Accounts)]
+pub struct CancelForceResolve<'info> {
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [OverrideRecord::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = override_record.bump
+    )]
+    pub override_record: Account<'info, OverrideRecord>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump
     )]
     pub admin: Account<'info, Admin>,
     #[account(mut)]
     pub authority: Signer<'info>,
+}
+
+/// Remaining accounts: signing secondary admin authorities
+#[derive(Accounts)]
+pub struct ForceResolve<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [OverrideRecord::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = override_record.bump
+    )]
+    pub override_record: Account<'info, OverrideRecord>,
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    #[account(
+        mut,
+        seeds = [DisputeEscrow::SEED.as_bytes(), dispute.key().as_ref()],
+        bump = dispute_escrow.bump
+    )]
+    pub dispute_escrow: Account<'info, DisputeEscrow>,
+    /// CHECK: This is the buyer
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    /// CHECK: This is the seller
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump
+    )]
+    pub admin: Account<'info, Admin>,
+    pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -119,34 +443,99 @@ pub fn open_dispute(ctx: Context<OpenDispute>, reason: String) -> Result<()> {
     }
 
     // Set respondent as the other party
-    let respondent_key = if offer.seller == initiator.key() {
-        offer.buyer.ok_or(ErrorCode::Unauthorized)?
+    let (initiator_role, respondent_key) = if offer.seller == initiator.key() {
+        (DisputeRole::Seller, offer.buyer.ok_or(ErrorCode::Unauthorized)?)
     } else {
-        offer.seller
+        (DisputeRole::Buyer, offer.seller)
     };
 
     if respondent_key != respondent.key() {
         return Err(error!(ErrorCode::Unauthorized));
     }
 
+    // Rate limit dispute opening, scaled by reputation tier
+    let reputation = &mut ctx.accounts.reputation;
+    let next_dispute_at = reputation.last_dispute_opened
+        .checked_add(reputation.scaled_cooldown(ctx.accounts.cooldown_config.dispute_opening_cooldown))
+        .ok_or(ErrorCode::MathOverflow)?;
+    if clock.unix_timestamp < next_dispute_at {
+        return Err(error!(ErrorCode::TooManyRequests));
+    }
+    reputation.last_dispute_opened = clock.unix_timestamp;
+
+    // The initiator posts the dispute fee up front
+    let dispute_fee = offer.amount
+        .checked_mul(Dispute::DISPUTE_FEE_BASIS_POINTS)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(ErrorCode::MathOverflow)?
+        .max(Dispute::MIN_DISPUTE_FEE);
+    let response_deadline = clock.unix_timestamp
+        .checked_add(ctx.accounts.dispute_config.timings.response_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let dispute_escrow = &mut ctx.accounts.dispute_escrow;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: initiator.to_account_info(),
+                to: dispute_escrow.to_account_info(),
+            },
+        ),
+        dispute_fee,
+    )?;
+
+    dispute_escrow.dispute = dispute.key();
+    dispute_escrow.initiator_fee = dispute_fee;
+    dispute_escrow.respondent_fee = 0;
+    dispute_escrow.appellant = Pubkey::default();
+    dispute_escrow.appeal_bond = 0;
+    dispute_escrow.bump = ctx.bumps.dispute_escrow;
+
     // Initialize dispute data
     dispute.offer = offer.key();
     dispute.initiator = initiator.key();
     dispute.respondent = respondent.key();
+    dispute.initiator_role = initiator_role as u8;
     dispute.reason = reason.clone();
     dispute.status = DisputeStatus::Opened as u8;
-    dispute.jurors = [Pubkey::default(); 3];
-    // Initialize evidence arrays with empty strings
-    for i in 0..MAX_EVIDENCE_ITEMS {
-        dispute.evidence_buyer[i] = String::new();
-        dispute.evidence_seller[i] = String::new();
-    }
-    dispute.evidence_buyer_count = 0;
-    dispute.evidence_seller_count = 0;
-    dispute.votes_for_buyer = 0;
-    dispute.votes_for_seller = 0;
+    dispute.panel_size = ctx.accounts.dispute_config.panel_size_for(offer.amount);
+    dispute.jurors = Vec::new();
+    dispute.panel_start = 0;
+    dispute.seated_at = Vec::new();
+    dispute.vacant_seats = 0;
+    dispute.replacement_draw_slot = 0;
+    dispute.vacated_jurors = Vec::new();
+    dispute.evidence_count = 0;
+    dispute.buyer_last_evidence_at = 0;
+    dispute.seller_last_evidence_at = 0;
+    dispute.votes_committed = 0;
+    dispute.buyer_allocations = Vec::new();
     dispute.created_at = clock.unix_timestamp;
     dispute.resolved_at = 0;
+    dispute.jurors_settled = 0;
+    dispute.draw_slot = 0; // Scheduled once the respondent matches the fee
+    dispute.draw_seed = [0u8; 32];
+    dispute.round_started_at = clock.unix_timestamp;
+    dispute.verdict_reached_at = 0;
+    dispute.appeal_round = 0;
+    dispute.appellant = None;
+    dispute.round_verdicts = Vec::new();
+    dispute.timings = ctx.accounts.dispute_config.timings;
+    dispute.evidence_ends_at = 0;
+    dispute.voting_ends_at = 0;
+    dispute.extension_requested_by = None;
+    dispute.extension_approvals = 0;
+    dispute.extension_granted = false;
+    dispute.dispute_fee = dispute_fee;
+    dispute.response_deadline = response_deadline;
+    dispute.respondent_fee_paid = false;
+    dispute.juror_fee_pool = 0;
+    dispute.settlement_proposer = None;
+    dispute.settlement_buyer_amount = 0;
+    dispute.settled_by_parties = false;
+    dispute.overridden = false;
 
     // Update offer to link to dispute
     offer.dispute_id = Some(dispute.key());
@@ -158,237 +547,468 @@ pub fn open_dispute(ctx: Context<OpenDispute>, reason: String) -> Result<()> {
         dispute: dispute.key(),
         offer: offer.key(),
         initiator: initiator.key(),
+        initiator_role: initiator_role as u8,
+        dispute_fee,
+        response_deadline,
         reason: reason.clone(),
     });
 
     Ok(())
 }
 
-pub fn assign_jurors(ctx: Context<AssignJurors>) -> Result<()> {
+pub fn pay_dispute_fee(ctx: Context<PayDisputeFee>) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
-    let juror1 = &ctx.accounts.juror1;
-    let juror2 = &ctx.accounts.juror2;
-    let juror3 = &ctx.accounts.juror3;
+    let dispute_escrow = &mut ctx.accounts.dispute_escrow;
+    let respondent = &ctx.accounts.respondent;
+    let clock = Clock::get()?;
 
     // Validate dispute status
     if dispute.status != DisputeStatus::Opened as u8 {
         return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
+    if dispute.respondent != respondent.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+    if dispute.respondent_fee_paid {
+        return Err(error!(ErrorCode::DisputeFeeAlreadyPaid));
+    }
+    if clock.unix_timestamp > dispute.response_deadline {
+        return Err(error!(ErrorCode::ResponseWindowClosed));
+    }
 
-    // Assign jurors
-    dispute.jurors[0] = juror1.key();
-    dispute.jurors[1] = juror2.key();
-    dispute.jurors[2] = juror3.key();
-    dispute.status = DisputeStatus::JurorsAssigned as u8;
+    // Match the initiator's fee
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: respondent.to_account_info(),
+                to: dispute_escrow.to_account_info(),
+            },
+        ),
+        dispute.dispute_fee,
+    )?;
+
+    dispute_escrow.respondent_fee = dispute.dispute_fee;
+    dispute.respondent_fee_paid = true;
+
+    // Jurors are drawn from a slot after the dispute became contested
+    dispute.draw_slot = clock.slot
+        .checked_add(Dispute::DRAW_DELAY_SLOTS)
+        .ok_or(ErrorCode::MathOverflow)?;
 
     // Emit event
-    emit!(JurorsAssigned {
+    emit!(DisputeFeePaid {
         dispute: dispute.key(),
-        jurors: dispute.jurors,
+        respondent: respondent.key(),
+        amount: dispute.dispute_fee,
     });
 
     Ok(())
 }
 
-pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_url: String) -> Result<()> {
-    // Input validation and sanitization
-    let evidence_url = validate_and_process_string(&evidence_url, MAX_EVIDENCE_URL_LEN)?;
-    if evidence_url.len() > MAX_EVIDENCE_URL_LEN {
-        return Err(error!(ErrorCode::InputTooLong));
-    }
-
+pub fn claim_default_judgment(ctx: Context<ClaimDefaultJudgment>) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
-    let submitter = &ctx.accounts.submitter;
+    let offer = &mut ctx.accounts.offer;
+    let escrow_account = &ctx.accounts.escrow_account;
+    let dispute_escrow = &ctx.accounts.dispute_escrow;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let clock = Clock::get()?;
 
-    // Validate dispute status
-    if dispute.status != DisputeStatus::JurorsAssigned as u8 && dispute.status != DisputeStatus::EvidenceSubmission as u8 {
+    // The respondent must have let the fee deadline pass
+    if dispute.status != DisputeStatus::Opened as u8 || dispute.respondent_fee_paid {
         return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
-
-    // Validate submitter is a party to the dispute
-    if dispute.initiator != submitter.key() && dispute.respondent != submitter.key() {
-        return Err(error!(ErrorCode::Unauthorized));
+    if clock.unix_timestamp <= dispute.response_deadline {
+        return Err(error!(ErrorCode::ResponseWindowOpen));
     }
 
-    // Add evidence to appropriate list
-    if dispute.initiator == submitter.key() {
-        if dispute.evidence_buyer_count >= MAX_EVIDENCE_ITEMS as u8 {
-            return Err(error!(ErrorCode::TooManyEvidenceItems));
-        }
-        let index = dispute.evidence_buyer_count as usize;
-        dispute.evidence_buyer[index] = evidence_url.clone();
-        dispute.evidence_buyer_count += 1;
-    } else {
-        if dispute.evidence_seller_count >= MAX_EVIDENCE_ITEMS as u8 {
-            return Err(error!(ErrorCode::TooManyEvidenceItems));
-        }
-        let index = dispute.evidence_seller_count as usize;
-        dispute.evidence_seller[index] = evidence_url.clone();
-        dispute.evidence_seller_count += 1;
-    }
+    validate_dispute_parties(dispute, offer, buyer, seller)?;
 
-    // Update status if first evidence submission
-    if dispute.status == DisputeStatus::JurorsAssigned as u8 {
-        dispute.status = DisputeStatus::EvidenceSubmission as u8;
-    }
+    // The initiator wins by default
+    let buyer_percentage = if dispute.initiator_role == DisputeRole::Buyer as u8 { 100 } else { 0 };
+    let (buyer_amount, seller_amount) = distribute_escrow(
+        escrow_account,
+        offer,
+        buyer,
+        seller,
+        buyer_payout(offer, buyer_percentage)?,
+    )?;
+
+    settle_dispute_fees(dispute, dispute_escrow, buyer, seller, None)?;
+
+    // Record the default judgment on both parties' reputations
+    record_dispute_outcome(
+        &mut ctx.accounts.buyer_reputation,
+        &mut ctx.accounts.seller_reputation,
+        buyer_percentage as u64,
+        100 - buyer_percentage as u64,
+        offer.amount,
+    )?;
 
     // Emit event
-    emit!(EvidenceSubmitted {
+    emit!(DefaultJudgment {
         dispute: dispute.key(),
-        submitter: submitter.key(),
-        evidence_url,
+        winner: dispute.initiator,
+        buyer_amount,
+        seller_amount,
     });
 
+    // Update dispute and offer status
+    dispute.status = DisputeStatus::Resolved as u8;
+    dispute.resolved_at = clock.unix_timestamp;
+    offer.status = OfferStatus::Completed as u8;
+    offer.updated_at = clock.unix_timestamp;
+
     Ok(())
 }
 
-pub fn cast_vote(ctx: Context<CastVote>, vote_for_buyer: bool) -> Result<()> {
+pub fn assign_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, AssignJurors<'info>>) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
-    let juror = &ctx.accounts.juror;
-    let vote = &mut ctx.accounts.vote;
+    let juror_registry = &ctx.accounts.juror_registry;
     let clock = Clock::get()?;
 
     // Validate dispute status
-    if dispute.status != DisputeStatus::EvidenceSubmission as u8 && dispute.status != DisputeStatus::Voting as u8 {
+    if dispute.status != DisputeStatus::Opened as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if !dispute.respondent_fee_paid {
+        return Err(error!(ErrorCode::RespondentFeeUnpaid));
+    }
+
+    // One juror stake per panel seat, followed by the signing secondary admin authorities
+    let panel_size = dispute.panel_size as usize;
+    if ctx.remaining_accounts.len() < panel_size {
+        return Err(error!(ErrorCode::InvalidPanelSize));
+    }
+    let (stake_accounts, signer_accounts) = ctx.remaining_accounts.split_at(panel_size);
+    collect_admin_signers(&ctx.accounts.admin, &ctx.accounts.authority.key(), signer_accounts)?;
+
+    // Parties, their recent counterparties and jurors of earlier rounds cannot judge the dispute
+    let excluded = conflicted_jurors(
+        dispute,
+        &ctx.accounts.initiator_trade_history,
+        &ctx.accounts.respondent_trade_history,
+    )?;
+
+    // Only distinct, registered, staked, non-suspended jurors can serve
+    let mut jurors: Vec<Pubkey> = Vec::with_capacity(panel_size);
+    for stake_info in stake_accounts.iter() {
+        if !stake_info.is_writable {
+            return Err(error!(ErrorCode::InvalidJurorStakeAccount));
+        }
+        let mut juror_stake = Account::<JurorStake>::try_from(stake_info)?;
+        let juror = juror_stake.juror;
+        if jurors.contains(&juror) {
+            return Err(error!(ErrorCode::DuplicateJuror));
+        }
+        if excluded.contains(&juror) {
+            return Err(error!(ErrorCode::JurorConflictOfInterest));
+        }
+        reserve_juror(juror_registry, &juror, &mut juror_stake)?;
+        juror_stake.exit(&crate::ID)?;
+        jurors.push(juror);
+    }
+
+    // Assign jurors (earlier rounds stay on record for stake settlement)
+    dispute.jurors.extend(jurors);
+    dispute.start_phase_clock(clock.unix_timestamp);
+    dispute.status = DisputeStatus::JurorsAssigned as u8;
+
+    // Emit event
+    emit!(JurorsAssigned {
+        dispute: dispute.key(),
+        jurors: dispute.current_panel().to_vec(),
+    });
+
+    Ok(())
+}
+
+pub fn submit_evidence(
+    ctx: Context<SubmitEvidence>,
+    content_hash: [u8; 32],
+    uri: String,
+    mime_type: String,
+) -> Result<()> {
+    // Input validation and sanitization
+    let uri = validate_and_process_string(&uri, MAX_EVIDENCE_URL_LEN)?;
+    let mime_type = validate_and_process_string(&mime_type, MAX_EVIDENCE_MIME_TYPE_LEN)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    let offer = &ctx.accounts.offer;
+    let evidence = &mut ctx.accounts.evidence;
+    let submitter = &ctx.accounts.submitter;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::JurorsAssigned as u8 && dispute.status != DisputeStatus::EvidenceSubmission as u8 {
         return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
 
-    // Critical security fix: Validate dispute deadlines to prevent indefinite locking
-    let current_time = clock.unix_timestamp;
-    let time_since_creation = current_time
-        .checked_sub(dispute.created_at)
+    // Evidence is only accepted during the evidence phase
+    if clock.unix_timestamp > dispute.evidence_ends_at {
+        return Err(error!(ErrorCode::EvidencePhaseClosed));
+    }
+
+    // Tag evidence by the submitter's trade role and rate-limit each party
+    let (role, last_evidence_at) = if offer.buyer == Some(submitter.key()) {
+        (DisputeRole::Buyer, &mut dispute.buyer_last_evidence_at)
+    } else if offer.seller == submitter.key() {
+        (DisputeRole::Seller, &mut dispute.seller_last_evidence_at)
+    } else {
+        return Err(error!(ErrorCode::Unauthorized));
+    };
+    if clock.unix_timestamp < last_evidence_at.saturating_add(Dispute::EVIDENCE_COOLDOWN) {
+        return Err(error!(ErrorCode::TooManyRequests));
+    }
+    *last_evidence_at = clock.unix_timestamp;
+
+    // Record the evidence item
+    evidence.dispute = dispute.key();
+    evidence.submitter = submitter.key();
+    evidence.role = role as u8;
+    evidence.index = dispute.evidence_count;
+    evidence.content_hash = content_hash;
+    evidence.uri = uri.clone();
+    evidence.mime_type = mime_type.clone();
+    evidence.submitted_at = clock.unix_timestamp;
+    evidence.bump = ctx.bumps.evidence;
+
+    dispute.evidence_count = dispute.evidence_count
+        .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
-    
-    // Check if total dispute deadline has passed
-    if time_since_creation > Dispute::TOTAL_DISPUTE_DEADLINE {
-        return Err(error!(ErrorCode::DisputeExpired));
+
+    // Update status if first evidence submission
+    if dispute.status == DisputeStatus::JurorsAssigned as u8 {
+        dispute.status = DisputeStatus::EvidenceSubmission as u8;
     }
-    
-    // Check voting phase deadline if in voting status
-    if dispute.status == DisputeStatus::Voting as u8 {
-        let voting_deadline = dispute.created_at
-            .checked_add(Dispute::EVIDENCE_SUBMISSION_DEADLINE)
-            .ok_or(ErrorCode::MathOverflow)?
-            .checked_add(Dispute::VOTING_DEADLINE)
+
+    // Emit event
+    emit!(EvidenceSubmitted {
+        dispute: dispute.key(),
+        evidence: evidence.key(),
+        submitter: submitter.key(),
+        role: role as u8,
+        index: evidence.index,
+        content_hash,
+        uri,
+        mime_type,
+    });
+
+    Ok(())
+}
+
+pub fn request_phase_extension(ctx: Context<RequestPhaseExtension>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let requester = &ctx.accounts.requester;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::JurorsAssigned as u8 && dispute.status != DisputeStatus::EvidenceSubmission as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if clock.unix_timestamp > dispute.evidence_ends_at {
+        return Err(error!(ErrorCode::EvidencePhaseClosed));
+    }
+
+    // Validate requester is a party to the dispute
+    if dispute.initiator != requester.key() && dispute.respondent != requester.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // Only a single extension per dispute
+    if dispute.extension_requested_by.is_some() {
+        return Err(error!(ErrorCode::ExtensionAlreadyRequested));
+    }
+    dispute.extension_requested_by = Some(requester.key());
+    dispute.extension_approvals = 0;
+
+    // Emit event
+    emit!(PhaseExtensionRequested {
+        dispute: dispute.key(),
+        requested_by: requester.key(),
+    });
+
+    Ok(())
+}
+
+pub fn approve_phase_extension(ctx: Context<ApprovePhaseExtension>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror = &ctx.accounts.juror;
+    let clock = Clock::get()?;
+
+    // Validate there is a pending request still inside the evidence phase
+    if dispute.extension_requested_by.is_none() || dispute.extension_granted {
+        return Err(error!(ErrorCode::NoPendingExtension));
+    }
+    if dispute.status != DisputeStatus::JurorsAssigned as u8 && dispute.status != DisputeStatus::EvidenceSubmission as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if clock.unix_timestamp > dispute.evidence_ends_at {
+        return Err(error!(ErrorCode::EvidencePhaseClosed));
+    }
+
+    // Validate juror is on the current panel
+    let seat = dispute.seat_of(&juror.key()).ok_or(ErrorCode::NotAJuror)?;
+    let seat_mask = 1u16 << seat;
+    if dispute.extension_approvals & seat_mask != 0 {
+        return Err(error!(ErrorCode::ExtensionAlreadyApproved));
+    }
+    dispute.extension_approvals |= seat_mask;
+    let approvals = dispute.extension_approvals.count_ones() as u8;
+
+    // Emit event
+    emit!(PhaseExtensionApproved {
+        dispute: dispute.key(),
+        juror: juror.key(),
+        approvals,
+    });
+
+    // A quorum of the panel grants the extension
+    if approvals >= dispute.quorum() {
+        dispute.evidence_ends_at = dispute.evidence_ends_at
+            .checked_add(Dispute::EXTENSION_DURATION)
             .ok_or(ErrorCode::MathOverflow)?;
-            
-        if current_time > voting_deadline {
-            return Err(error!(ErrorCode::DisputeExpired));
-        }
+        dispute.voting_ends_at = dispute.voting_ends_at
+            .checked_add(Dispute::EXTENSION_DURATION)
+            .ok_or(ErrorCode::MathOverflow)?;
+        dispute.extension_granted = true;
+
+        emit!(PhasesExtended {
+            dispute: dispute.key(),
+            evidence_ends_at: dispute.evidence_ends_at,
+            voting_ends_at: dispute.voting_ends_at,
+        });
     }
 
-    // Validate juror is assigned to this dispute
-    if !dispute.jurors.contains(&juror.key()) {
-        return Err(error!(ErrorCode::NotAJuror));
+    Ok(())
+}
+
+pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror = &ctx.accounts.juror;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::JurorsAssigned as u8
+        && dispute.status != DisputeStatus::EvidenceSubmission as u8
+        && dispute.status != DisputeStatus::Voting as u8
+    {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
 
-    // Additional security: Verify juror index and ensure no manipulation
-    let juror_index = dispute.jurors.iter().position(|&x| x == juror.key())
-        .ok_or(ErrorCode::NotAJuror)?;
-        
-    // Ensure juror index is valid (0, 1, or 2 for our 3-juror system)
-    if juror_index >= 3 {
-        return Err(error!(ErrorCode::NotAJuror));
+    // Commits are only accepted between the end of the evidence phase and the commit deadline
+    if clock.unix_timestamp <= dispute.evidence_ends_at {
+        return Err(error!(ErrorCode::VotingNotOpen));
     }
-    // Double-check that this specific juror hasn't been counted already
-    // This is extra safety on top of PDA-based duplicate prevention
-    let mut vote_count = 0;
-    if dispute.votes_for_buyer > 0 || dispute.votes_for_seller > 0 {
-        vote_count = dispute.votes_for_buyer + dispute.votes_for_seller;
+    if clock.unix_timestamp > dispute.voting_ends_at {
+        return Err(error!(ErrorCode::DisputeExpired));
     }
-    
-    // Sanity check: total votes should never exceed number of jurors
-    if vote_count >= 3 {
+
+    // Validate juror holds a seat on the current panel
+    if dispute.seat_of(&juror.key()).is_none() {
+        return Err(error!(ErrorCode::NotAJuror));
+    }
+
+    // Sanity check: commits should never exceed number of jurors
+    if dispute.votes_committed >= dispute.panel_size {
         return Err(error!(ErrorCode::AlreadyVoted));
     }
-    
-    // Validate that the juror has not already voted
-    // Check by using Vote PDA - if the account exists, they've already voted
-    // This is handled by the PDA constraint in the CastVote accounts struct=======
-    // Note: PDA-based duplicate prevention ensures jurors can't vote twice
-    // The vote account PDA will fail to initialize if the juror already voted
-    // Initialize vote data (PDA prevents duplicate votes)
+
+    // Initialize vote data (PDA prevents duplicate commits)
     vote.dispute = dispute.key();
     vote.juror = juror.key();
-    vote.vote_for_buyer = vote_for_buyer;
+    vote.commitment = commitment;
+    vote.revealed = false;
+    vote.buyer_percentage = 0; // Unknown until revealed
     vote.timestamp = clock.unix_timestamp;
+    vote.revealed_at = 0;
 
-    // Critical security fix: Atomic vote counting with validation
-    // Store original vote counts for potential rollback scenarios if needed
-    let _original_votes_for_buyer = dispute.votes_for_buyer;
-    let _original_votes_for_seller = dispute.votes_for_seller;
-    let original_status = dispute.status;
-
-    // Validate current vote count totals don't exceed maximum possible (3 jurors)
-    let total_votes = dispute.votes_for_buyer
-        .checked_add(dispute.votes_for_seller)
+    dispute.votes_committed = dispute.votes_committed
+        .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
-    
-    if total_votes >= 3 {
+    if dispute.status != DisputeStatus::Voting as u8 {
+        dispute.status = DisputeStatus::Voting as u8;
+    }
+
+    // Emit event (the vote itself stays hidden until reveal)
+    emit!(VoteCommitted {
+        dispute: dispute.key(),
+        juror: juror.key(),
+    });
+
+    Ok(())
+}
+
+pub fn reveal_vote(ctx: Context<RevealVote>, buyer_percentage: u8, salt: [u8; 32]) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror = &ctx.accounts.juror;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::Voting as u8 {
         return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
 
-    // Update vote counts atomically
-    if vote_for_buyer {
-        dispute.votes_for_buyer = dispute.votes_for_buyer
-            .checked_add(1)
-            .ok_or(ErrorCode::MathOverflow)?;
-    } else {
-        dispute.votes_for_seller = dispute.votes_for_seller
-            .checked_add(1)
-            .ok_or(ErrorCode::MathOverflow)?;
+    // Reveals open once every juror committed or the commit deadline passed
+    if dispute.votes_committed < dispute.panel_size && clock.unix_timestamp <= dispute.voting_ends_at {
+        return Err(error!(ErrorCode::RevealNotOpen));
+    }
+    if clock.unix_timestamp > dispute.reveal_ends_at() {
+        return Err(error!(ErrorCode::DisputeExpired));
     }
 
-    // Update status transitions atomically
-    let new_total_votes = dispute.votes_for_buyer
-        .checked_add(dispute.votes_for_seller)
-        .ok_or(ErrorCode::MathOverflow)?;
+    if vote.revealed {
+        return Err(error!(ErrorCode::AlreadyVoted));
+    }
 
-    // Update status if first vote
-    if original_status == DisputeStatus::EvidenceSubmission as u8 && new_total_votes == 1 {
-        dispute.status = DisputeStatus::Voting as u8;
+    // Votes from an earlier round no longer count
+    if !dispute.current_panel().contains(&juror.key()) {
+        return Err(error!(ErrorCode::NotAJuror));
+    }
+
+    // The revealed vote must match the commitment
+    if vote_commitment(&dispute.key(), &juror.key(), buyer_percentage, &salt) != vote.commitment {
+        return Err(error!(ErrorCode::InvalidVoteReveal));
     }
+    if buyer_percentage > 100 {
+        return Err(error!(ErrorCode::InvalidAllocation));
+    }
+
+    vote.buyer_percentage = buyer_percentage;
+    vote.revealed = true;
+    vote.revealed_at = clock.unix_timestamp;
 
-    // Check if verdict is reached (majority of 3)
-    if dispute.votes_for_buyer >= 2 || dispute.votes_for_seller >= 2 {
+    // Votes are only tallied once revealed
+    dispute.buyer_allocations.push(buyer_percentage);
+
+    // The last reveal makes the median final; with reveals still missing the verdict
+    // waits for the reveal phase to end (see `Dispute::has_verdict`)
+    if dispute.buyer_allocations.len() >= dispute.panel_size as usize {
         dispute.status = DisputeStatus::VerdictReached as u8;
+        dispute.verdict_reached_at = clock.unix_timestamp;
     }
 
     // Emit event
-    emit!(VoteCast {
+    emit!(VoteRevealed {
         dispute: dispute.key(),
         juror: juror.key(),
-        vote_for_buyer,
+        buyer_percentage,
     });
 
-    // Try to mint governance rewards for voting (optional - fails silently if reward system not set up)
-    let _ = try_mint_vote_rewards_for_juror(&juror.key());
-
     Ok(())
 }
 
-// Helper function to mint governance rewards after voting
-fn try_mint_vote_rewards_for_juror(juror: &Pubkey) -> Result<()> {
-    let clock = Clock::get()?;
-    let users = vec![*juror];
-    
-    // Rate limiting: Governance votes are naturally rate-limited by dispute frequency
-    // Only emit one event per vote to prevent spamming
-    emit!(RewardEligible {
-        users: users.clone(),
-        trade_volume: 0, // Not applicable for governance rewards
-        reward_type: "vote".to_string(),
-        timestamp: clock.unix_timestamp,
-    });
-    
-    // Note: Actual reward minting should be done via separate instructions
-    // This maintains backward compatibility while enabling monitoring
-    msg!("Governance vote cast - eligible for rewards. Juror: {}", juror);
-    
-    Ok(())
+/// Computes the commitment a juror submits in `commit_vote`
+///
+/// `sha256(dispute || juror || buyer_percentage || salt)`
+pub fn vote_commitment(dispute: &Pubkey, juror: &Pubkey, buyer_percentage: u8, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[dispute.as_ref(), juror.as_ref(), &[buyer_percentage], salt]).to_bytes()
 }
 
-pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
+pub fn resolve_expired_dispute(ctx: Context<ResolveExpiredDispute>) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
     let offer = &mut ctx.accounts.offer;
     let escrow_account = &ctx.accounts.escrow_account;
@@ -396,11 +1016,96 @@ pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
     let seller = &ctx.accounts.seller;
     let clock = Clock::get()?;
 
-    // Validate dispute status
-    if dispute.status != DisputeStatus::VerdictReached as u8 {
+    // Disputes with a verdict are settled through execute_verdict
+    if dispute.has_verdict(clock.unix_timestamp) || dispute.status == DisputeStatus::Resolved as u8 {
         return Err(error!(ErrorCode::InvalidDisputeStatus));
     }
 
+    // Uncontested disputes are settled through claim_default_judgment
+    if !dispute.respondent_fee_paid {
+        return Err(error!(ErrorCode::RespondentFeeUnpaid));
+    }
+
+    // The current round must have run out of time
+    if clock.unix_timestamp <= dispute.reveal_ends_at() {
+        return Err(error!(ErrorCode::DisputeNotExpired));
+    }
+
+    validate_dispute_parties(dispute, offer, buyer, seller)?;
+
+    // A quorum of reveals is executed as a verdict, so the revealed votes here never
+    // decide the escrow. Default policy: the appealed verdict, else refund each party's own deposit
+    let (policy, buyer_percentage) = if dispute.appellant.is_some() {
+        (ExpiredDisputePolicy::AppealedVerdict, Some(dispute.appealed_buyer_percentage))
+    } else {
+        (ExpiredDisputePolicy::Refund, None)
+    };
+    let buyer_amount = match buyer_percentage {
+        Some(buyer_percentage) => buyer_payout(offer, buyer_percentage)?,
+        None => offer.security_bond,
+    };
+
+    let (buyer_amount, seller_amount) = distribute_escrow(
+        escrow_account,
+        offer,
+        buyer,
+        seller,
+        buyer_amount,
+    )?;
+
+    // The appeal panel missed its quorum through no fault of the appellant, so the bond
+    // goes back to the appellant
+    settle_appeal_bond(
+        dispute,
+        &ctx.accounts.dispute_escrow,
+        buyer,
+        seller,
+        None,
+    )?;
+
+    // Without a quorum no juror is owed compensation, so both fees are refunded
+    settle_dispute_fees(
+        dispute,
+        &ctx.accounts.dispute_escrow,
+        buyer,
+        seller,
+        buyer_percentage,
+    )?;
+
+    // Record the outcome on both parties' reputations; a refund decides nothing and counts as a split
+    let outcome_percentage = buyer_percentage.unwrap_or(50);
+    record_dispute_outcome(
+        &mut ctx.accounts.buyer_reputation,
+        &mut ctx.accounts.seller_reputation,
+        outcome_percentage as u64,
+        100 - outcome_percentage as u64,
+        offer.amount,
+    )?;
+
+    // Emit event
+    emit!(ExpiredDisputeResolved {
+        dispute: dispute.key(),
+        policy: policy as u8,
+        buyer_amount,
+        seller_amount,
+    });
+
+    // Update dispute and offer status
+    dispute.status = DisputeStatus::Resolved as u8;
+    dispute.resolved_at = clock.unix_timestamp;
+    offer.status = OfferStatus::Completed as u8;
+    offer.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+// Helper function to validate the dispute, offer, buyer and seller belong together
+fn validate_dispute_parties(
+    dispute: &Dispute,
+    offer: &Account<'_, Offer>,
+    buyer: &AccountInfo<'_>,
+    seller: &AccountInfo<'_>,
+) -> Result<()> {
     // Critical security fix: Validate that the dispute belongs to this offer
     if dispute.offer != offer.key() {
         return Err(error!(ErrorCode::Unauthorized));
@@ -418,9 +1123,19 @@ pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
         return Err(error!(ErrorCode::Unauthorized));
     }
 
-    // Determine winner and transfer funds accordingly
+    Ok(())
+}
+
+// Helper function to pay out the trade escrow: the buyer receives `buyer_amount`, the seller the rest
+fn distribute_escrow<'info>(
+    escrow_account: &Account<'info, EscrowAccount>,
+    offer: &Account<'info, Offer>,
+    buyer: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    buyer_amount: u64,
+) -> Result<(u64, u64)> {
     let escrow_balance = escrow_account.to_account_info().lamports();
-    
+
     // Critical security fix: Validate minimum balance and expected amount
     let minimum_rent_exempt = Rent::get()?.minimum_balance(EscrowAccount::LEN + 8);
     if escrow_balance <= minimum_rent_exempt {
@@ -433,10 +1148,10 @@ pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
         .ok_or(ErrorCode::MathOverflow)?
         .checked_add(minimum_rent_exempt)
         .ok_or(ErrorCode::MathOverflow)?;
-    
+
     // Allow some tolerance for potential small discrepancies in rent calculations
     let balance_tolerance = 1000; // Allow up to 1000 lamports difference for rent calculation variations
-    if escrow_balance < expected_balance.saturating_sub(balance_tolerance) || 
+    if escrow_balance < expected_balance.saturating_sub(balance_tolerance) ||
        escrow_balance > expected_balance.saturating_add(balance_tolerance) {
         return Err(error!(ErrorCode::InvalidEscrowBalance));
     }
@@ -445,58 +1160,788 @@ pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
     let transferable_amount = escrow_balance
         .checked_sub(minimum_rent_exempt)
         .ok_or(ErrorCode::MathOverflow)?;
-    
-    if transferable_amount > 0 {
-        // Explicit tie-breaking logic: ties are rejected
-        let recipient = if dispute.votes_for_buyer > dispute.votes_for_seller {
-            buyer // Buyer wins
-        } else if dispute.votes_for_seller > dispute.votes_for_buyer {
-            seller // Seller wins
-        } else {
-            // Tie case: reject the verdict execution
-            return Err(error!(ErrorCode::TiedVote));
-        };
 
-        // Critical security fix: Validate transfer amount doesn't exceed expected
-        if transferable_amount > offer.amount.checked_add(offer.security_bond).ok_or(ErrorCode::MathOverflow)? {
-            return Err(error!(ErrorCode::InvalidAmount));
+    // Critical security fix: Validate transfer amount doesn't exceed expected
+    if transferable_amount > offer.amount.checked_add(offer.security_bond).ok_or(ErrorCode::MathOverflow)? {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+
+    let buyer_amount = buyer_amount.min(transferable_amount);
+    let seller_amount = transferable_amount
+        .checked_sub(buyer_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    transfer_from_escrow(escrow_account, buyer, buyer_amount)?;
+    transfer_from_escrow(escrow_account, seller, seller_amount)?;
+
+    Ok((buyer_amount, seller_amount))
+}
+
+// Helper function to settle the appeal bond: refunded if the appeal moved the verdict
+// in the appellant's favor or the appeal round reached no verdict (`None`), otherwise
+// forfeited to the juror fee pool
+fn settle_appeal_bond<'info>(
+    dispute: &mut Account<'info, Dispute>,
+    dispute_escrow: &Account<'info, DisputeEscrow>,
+    buyer: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    buyer_percentage: Option<u8>,
+) -> Result<()> {
+    let Some(appellant) = dispute.appellant else {
+        return Ok(());
+    };
+
+    let appellant_is_buyer = appellant == buyer.key();
+    let refunded = match buyer_percentage {
+        Some(buyer_percentage) if appellant_is_buyer => buyer_percentage > dispute.appealed_buyer_percentage,
+        Some(buyer_percentage) => buyer_percentage < dispute.appealed_buyer_percentage,
+        None => true,
+    };
+
+    let dispute_key = dispute.key();
+    let recipient = if refunded {
+        transfer_from_pda(
+            &dispute_escrow.to_account_info(),
+            if appellant_is_buyer { buyer } else { seller },
+            dispute_escrow.appeal_bond,
+        )?;
+        appellant
+    } else {
+        // Forfeited bonds stay in the escrow and compensate the jurors
+        dispute.juror_fee_pool = dispute.juror_fee_pool
+            .checked_add(dispute_escrow.appeal_bond)
+            .ok_or(ErrorCode::MathOverflow)?;
+        dispute_escrow.key()
+    };
+
+    // Emit event
+    emit!(AppealBondSettled {
+        dispute: dispute_key,
+        appellant,
+        recipient,
+        amount: dispute_escrow.appeal_bond,
+        refunded,
+    });
+
+    Ok(())
+}
+
+// Helper function to settle the dispute fees: each party pays jurors the share it lost and
+// gets the rest refunded; without a quorum of revealed votes both fees are refunded in full
+fn settle_dispute_fees<'info>(
+    dispute: &mut Account<'info, Dispute>,
+    dispute_escrow: &Account<'info, DisputeEscrow>,
+    buyer: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    buyer_percentage: Option<u8>,
+) -> Result<()> {
+    let (buyer_fee, seller_fee) = if dispute.initiator_role == DisputeRole::Buyer as u8 {
+        (dispute_escrow.initiator_fee, dispute_escrow.respondent_fee)
+    } else {
+        (dispute_escrow.respondent_fee, dispute_escrow.initiator_fee)
+    };
+
+    let (buyer_cost, seller_cost) = match buyer_percentage {
+        Some(buyer_percentage) if dispute.buyer_allocations.len() >= dispute.quorum() as usize => (
+            proportional_share(buyer_fee, 100 - buyer_percentage)?,
+            proportional_share(seller_fee, buyer_percentage)?,
+        ),
+        _ => (0, 0),
+    };
+    let buyer_refund = buyer_fee
+        .checked_sub(buyer_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let seller_refund = seller_fee
+        .checked_sub(seller_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let dispute_key = dispute.key();
+    transfer_from_pda(&dispute_escrow.to_account_info(), buyer, buyer_refund)?;
+    transfer_from_pda(&dispute_escrow.to_account_info(), seller, seller_refund)?;
+
+    // The lost shares stay in the escrow and compensate the jurors
+    dispute.juror_fee_pool = dispute.juror_fee_pool
+        .checked_add(buyer_cost)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(seller_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Emit event
+    emit!(DisputeFeesSettled {
+        dispute: dispute_key,
+        buyer_refund,
+        seller_refund,
+        juror_fee_pool: dispute.juror_fee_pool,
+    });
+
+    Ok(())
+}
+
+// Helper function to pay the juror fee pool to the jurors who voted with the final majority
+// and credit their vote rewards; jurors who did not vote or voted with the minority get nothing.
+// Whatever the majority jurors are not paid (all of it without a majority, else the rounding
+// remainder) is forfeited to the juror registry
+//
+// Remaining accounts: `[juror, vote, user_rewards]` for every seat of the current panel
+fn compensate_majority_jurors<'info>(
+    dispute: &Account<'info, Dispute>,
+    dispute_escrow: &Account<'info, DisputeEscrow>,
+    juror_registry: &mut Account<'info, JurorRegistry>,
+    reward_token: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    let panel = dispute.current_panel();
+    if remaining_accounts.len() != panel.len() * 3 {
+        return Err(error!(ErrorCode::InvalidJurorAccounts));
+    }
+
+    // Find the jurors whose revealed allocation agrees with the verdict
+    let dispute_key = dispute.key();
+    let mut majority_jurors = Vec::with_capacity(panel.len());
+    let mut revealed_votes = 0;
+    for (index, (juror, accounts)) in panel.iter().zip(remaining_accounts.chunks(3)).enumerate() {
+        if accounts[0].key() != *juror {
+            return Err(error!(ErrorCode::InvalidJurorAccounts));
+        }
+        if accounts[1].data_is_empty() {
+            continue;
+        }
+        if accounts[1].owner != &crate::ID {
+            return Err(error!(ErrorCode::Unauthorized));
         }
 
-        let transfer_instruction = system_instruction::transfer(
-            &escrow_account.key(),
-            &recipient.key(),
-            transferable_amount,
-        );
+        // Votes only exist at their PDA, so a vote for this dispute and juror is the juror's vote
+        let vote = Vote::try_deserialize(&mut &accounts[1].try_borrow_data()?[..])?;
+        if vote.dispute != dispute_key || vote.juror != *juror {
+            return Err(error!(ErrorCode::InvalidJurorAccounts));
+        }
+        if !vote.revealed {
+            continue;
+        }
+        revealed_votes += 1;
+
+        let seat = dispute.panel_start as usize + index;
+        if !dispute.is_minority_allocation(seat, vote.buyer_percentage) {
+            majority_jurors.push(accounts);
+        }
+    }
+
+    // Every revealed vote must be passed, so no majority juror can be left out
+    if revealed_votes != dispute.buyer_allocations.len() {
+        return Err(error!(ErrorCode::InvalidJurorAccounts));
+    }
 
-        let escrow_seeds = &[
-            EscrowAccount::SEED.as_bytes(),
-            &offer.key().to_bytes(),
-            &[escrow_account.bump],
-        ];
-
-        invoke_signed(
-            &transfer_instruction,
-            &[
-                escrow_account.to_account_info(),
-                recipient.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            &[escrow_seeds],
+    let fee_share = if majority_jurors.is_empty() {
+        0
+    } else {
+        dispute.juror_fee_pool
+            .checked_div(majority_jurors.len() as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+    };
+    let reward_amount = load_vote_reward_rate(reward_token)?;
+
+    for accounts in majority_jurors.iter() {
+        let juror = &accounts[0];
+        if !juror.is_writable {
+            return Err(error!(ErrorCode::InvalidJurorAccounts));
+        }
+        transfer_from_pda(
+            &dispute_escrow.to_account_info(),
+            juror,
+            fee_share,
         )?;
 
-        // Emit event with actual transferred amount
-        emit!(VerdictExecuted {
-            dispute: dispute.key(),
-            winner: recipient.key(),
-            amount: transferable_amount,
+        // Credit vote rewards to jurors with a rewards account
+        let reward_credited = if reward_amount > 0 {
+            credit_vote_reward(juror.key(), &accounts[2], reward_amount, now)?
+        } else {
+            0
+        };
+
+        // Emit event
+        emit!(JurorCompensated {
+            dispute: dispute_key,
+            juror: juror.key(),
+            amount: fee_share,
+            reward_amount: reward_credited,
+        });
+    }
+
+    // The unpaid part of the pool must not stay locked in the dispute escrow
+    let paid_fees = fee_share
+        .checked_mul(majority_jurors.len() as u64)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let forfeited_fees = dispute.juror_fee_pool
+        .checked_sub(paid_fees)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if forfeited_fees > 0 {
+        transfer_from_pda(
+            &dispute_escrow.to_account_info(),
+            &juror_registry.to_account_info(),
+            forfeited_fees,
+        )?;
+        juror_registry.forfeited_lamports = juror_registry.forfeited_lamports
+            .checked_add(forfeited_fees)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // Emit event
+        emit!(JurorFeesForfeited {
+            dispute: dispute_key,
+            amount: forfeited_fees,
         });
     }
 
+    Ok(())
+}
+
+// Helper function to read the per-vote reward rate; zero if the reward system is not set up
+fn load_vote_reward_rate(reward_token: &AccountInfo) -> Result<u64> {
+    if reward_token.data_is_empty() {
+        return Ok(0);
+    }
+    if reward_token.owner != &crate::ID {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    let reward_token = RewardToken::try_deserialize(&mut &reward_token.try_borrow_data()?[..])?;
+    Ok(reward_token.reward_rate_per_vote)
+}
+
+// Helper function to credit a vote reward to a juror's `UserRewards` PDA, if the juror
+// created one; returns the amount credited
+fn credit_vote_reward<'info>(
+    juror: Pubkey,
+    rewards_info: &'info AccountInfo<'info>,
+    reward_amount: u64,
+    now: i64,
+) -> Result<u64> {
+    // An empty account must be the juror's PDA, so no created rewards account can be skipped
+    if rewards_info.data_is_empty() {
+        let (expected_rewards, _) = Pubkey::find_program_address(
+            &[UserRewards::SEED.as_bytes(), juror.as_ref()],
+            &crate::ID,
+        );
+        if rewards_info.key() != expected_rewards {
+            return Err(error!(ErrorCode::InvalidJurorAccounts));
+        }
+        return Ok(0);
+    }
+    if !rewards_info.is_writable {
+        return Err(error!(ErrorCode::InvalidJurorAccounts));
+    }
+
+    // Rewards accounts only exist at their PDA, so the juror's account is the PDA
+    let mut user_rewards = Account::<UserRewards>::try_from(rewards_info)?;
+    if user_rewards.user != juror {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    user_rewards.total_earned = user_rewards.total_earned
+        .checked_add(reward_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_rewards.unclaimed_balance = user_rewards.unclaimed_balance
+        .checked_add(reward_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_rewards.governance_votes = user_rewards.governance_votes
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_rewards.last_vote_reward = now;
+    user_rewards.exit(&crate::ID)?;
+
+    emit!(RewardsEarned {
+        user: juror,
+        amount: reward_amount,
+        reason: "vote".to_string(),
+        timestamp: now,
+    });
+
+    Ok(reward_amount)
+}
+
+// Helper function to record a resolved dispute on both parties' reputations: the party
+// awarded the larger share wins, an even split counts as a split for both
+fn record_dispute_outcome(
+    buyer_reputation: &mut Reputation,
+    seller_reputation: &mut Reputation,
+    buyer_share: u64,
+    seller_share: u64,
+    trade_amount: u64,
+) -> Result<()> {
+    let (buyer_outcome, seller_outcome) = match buyer_share.cmp(&seller_share) {
+        Ordering::Greater => (TradeOutcome::DisputeWon, TradeOutcome::DisputeLost),
+        Ordering::Less => (TradeOutcome::DisputeLost, TradeOutcome::DisputeWon),
+        Ordering::Equal => (TradeOutcome::DisputeSplit, TradeOutcome::DisputeSplit),
+    };
+    record_trade_outcome(buyer_reputation, buyer_outcome, trade_amount)?;
+    record_trade_outcome(seller_reputation, seller_outcome, trade_amount)?;
+
+    Ok(())
+}
+
+// Helper function to compute the buyer's share of amount and security bond for an allocation
+fn buyer_payout(offer: &Offer, buyer_percentage: u8) -> Result<u64> {
+    Ok(proportional_share(offer.amount, buyer_percentage)?
+        .checked_add(proportional_share(offer.security_bond, buyer_percentage)?)
+        .ok_or(ErrorCode::MathOverflow)?)
+}
+
+// Helper function to compute a percentage share of an amount
+fn proportional_share(amount: u64, percentage: u8) -> Result<u64> {
+    Ok(amount
+        .checked_mul(percentage as u64)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(100)
+        .ok_or(ErrorCode::MathOverflow)?)
+}
+
+pub fn propose_settlement(ctx: Context<ProposeSettlement>, buyer_amount: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let offer = &ctx.accounts.offer;
+    let proposer = &ctx.accounts.proposer;
+    let clock = Clock::get()?;
+
+    // Settlements are possible until a verdict is reached
+    if dispute.has_verdict(clock.unix_timestamp) || dispute.status == DisputeStatus::Resolved as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+
+    // Validate proposer is a party to the dispute
+    if dispute.initiator != proposer.key() && dispute.respondent != proposer.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // The split covers the whole escrow (amount plus security bond)
+    let escrowed_amount = offer.amount
+        .checked_add(offer.security_bond)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if buyer_amount > escrowed_amount {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+
+    // A new proposal replaces any pending one
+    dispute.settlement_proposer = Some(proposer.key());
+    dispute.settlement_buyer_amount = buyer_amount;
+
+    // Emit event
+    emit!(SettlementProposed {
+        dispute: dispute.key(),
+        proposer: proposer.key(),
+        buyer_amount,
+        seller_amount: escrowed_amount - buyer_amount,
+    });
+
+    Ok(())
+}
+
+pub fn accept_settlement(ctx: Context<AcceptSettlement>, expected_buyer_amount: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let offer = &mut ctx.accounts.offer;
+    let escrow_account = &ctx.accounts.escrow_account;
+    let dispute_escrow = &ctx.accounts.dispute_escrow;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let accepter = &ctx.accounts.accepter;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.has_verdict(clock.unix_timestamp) || dispute.status == DisputeStatus::Resolved as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+
+    // Only the counterparty of the proposer can accept
+    let proposer = dispute.settlement_proposer.ok_or(ErrorCode::NoPendingSettlement)?;
+    if accepter.key() == proposer
+        || (dispute.initiator != accepter.key() && dispute.respondent != accepter.key())
+    {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // Guard against the proposal changing before acceptance lands
+    if dispute.settlement_buyer_amount != expected_buyer_amount {
+        return Err(error!(ErrorCode::SettlementTermsChanged));
+    }
+
+    validate_dispute_parties(dispute, offer, buyer, seller)?;
+
+    let (buyer_amount, seller_amount) = distribute_escrow(
+        escrow_account,
+        offer,
+        buyer,
+        seller,
+        dispute.settlement_buyer_amount,
+    )?;
+
+    // No juror work is owed: the appeal bond and both fees are refunded
+    settle_appeal_bond(dispute, dispute_escrow, buyer, seller, None)?;
+    settle_dispute_fees(dispute, dispute_escrow, buyer, seller, None)?;
+
+    // Record the settlement on both parties' reputations
+    record_dispute_outcome(
+        &mut ctx.accounts.buyer_reputation,
+        &mut ctx.accounts.seller_reputation,
+        buyer_amount,
+        seller_amount,
+        offer.amount,
+    )?;
+
+    // Emit event
+    emit!(SettlementAccepted {
+        dispute: dispute.key(),
+        accepter: accepter.key(),
+        buyer_amount,
+        seller_amount,
+    });
+
     // Update dispute and offer status
+    dispute.settled_by_parties = true;
     dispute.status = DisputeStatus::Resolved as u8;
     dispute.resolved_at = clock.unix_timestamp;
     offer.status = OfferStatus::Completed as u8;
     offer.updated_at = clock.unix_timestamp;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn appeal_verdict(ctx: Context<AppealVerdict>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let offer = &ctx.accounts.offer;
+    let dispute_escrow = &mut ctx.accounts.dispute_escrow;
+    let appellant = &ctx.accounts.appellant;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if !dispute.has_verdict(clock.unix_timestamp) {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if dispute.appeal_round >= MAX_APPEAL_ROUNDS {
+        return Err(error!(ErrorCode::AppealNotAvailable));
+    }
+
+    // The appeal window opens once the median is final
+    let appeal_deadline = dispute.verdict_final_at()
+        .checked_add(dispute.timings.appeal_window)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if clock.unix_timestamp > appeal_deadline {
+        return Err(error!(ErrorCode::AppealWindowClosed));
+    }
+
+    // Only a party that did not receive the full escrow can appeal
+    let buyer_percentage = dispute.median_buyer_percentage()
+        .ok_or(ErrorCode::InvalidDisputeStatus)?;
+    let buyer = offer.buyer.ok_or(ErrorCode::Unauthorized)?;
+    let can_appeal = (appellant.key() == buyer && buyer_percentage < 100)
+        || (appellant.key() == offer.seller && buyer_percentage > 0);
+    if !can_appeal {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // Appeals go to a larger panel
+    let appeal_panel_size = dispute.panel_size
+        .saturating_mul(2)
+        .saturating_add(1)
+        .min(MAX_PANEL_SIZE);
+    if appeal_panel_size <= dispute.panel_size {
+        return Err(error!(ErrorCode::AppealNotAvailable));
+    }
+
+    // Post the appeal bond
+    let appeal_bond = offer.amount
+        .checked_mul(Dispute::APPEAL_BOND_BASIS_POINTS)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(ErrorCode::MathOverflow)?
+        .max(Dispute::MIN_APPEAL_BOND);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: appellant.to_account_info(),
+                to: dispute_escrow.to_account_info(),
+            },
+        ),
+        appeal_bond,
+    )?;
+
+    dispute_escrow.appellant = appellant.key();
+    dispute_escrow.appeal_bond = appeal_bond;
+
+    // Start a fresh round; the new verdict supersedes the original
+    dispute.appeal_round = dispute.appeal_round
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    dispute.appellant = Some(appellant.key());
+    dispute.appealed_buyer_percentage = buyer_percentage;
+    let panel_end = dispute.jurors.len() as u8;
+    dispute.round_verdicts.push(RoundVerdict {
+        panel_end,
+        buyer_percentage,
+    });
+    dispute.panel_start = dispute.jurors.len() as u8;
+    dispute.panel_size = appeal_panel_size;
+    dispute.votes_committed = 0;
+    dispute.buyer_allocations = Vec::new();
+    dispute.verdict_reached_at = 0;
+    dispute.round_started_at = clock.unix_timestamp;
+    dispute.evidence_ends_at = 0;
+    dispute.voting_ends_at = 0;
+    dispute.draw_slot = clock.slot
+        .checked_add(Dispute::DRAW_DELAY_SLOTS)
+        .ok_or(ErrorCode::MathOverflow)?;
+    dispute.draw_seed = [0u8; 32];
+    dispute.status = DisputeStatus::Opened as u8;
+
+    // Emit event
+    emit!(DisputeAppealed {
+        dispute: dispute.key(),
+        appellant: appellant.key(),
+        appeal_bond,
+        appeal_round: dispute.appeal_round,
+        panel_size: appeal_panel_size,
+    });
+
+    Ok(())
+}
+
+pub fn execute_verdict<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteVerdict<'info>>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let offer = &mut ctx.accounts.offer;
+    let escrow_account = &ctx.accounts.escrow_account;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let clock = Clock::get()?;
+
+    // The median is final once every juror revealed or the reveal phase ended
+    if dispute.status == DisputeStatus::Voting as u8 && clock.unix_timestamp <= dispute.reveal_ends_at() {
+        return Err(error!(ErrorCode::RevealStillOpen));
+    }
+
+    // Validate dispute status
+    if !dispute.has_verdict(clock.unix_timestamp) {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+
+    // The losing party gets a chance to appeal before funds move
+    if dispute.appeal_round < MAX_APPEAL_ROUNDS {
+        let appeal_deadline = dispute.verdict_final_at()
+            .checked_add(dispute.timings.appeal_window)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if clock.unix_timestamp <= appeal_deadline {
+            return Err(error!(ErrorCode::AppealWindowOpen));
+        }
+    }
+
+    validate_dispute_parties(dispute, offer, buyer, seller)?;
+
+    // Split amount and security bond by the median juror allocation
+    let buyer_percentage = dispute.median_buyer_percentage()
+        .ok_or(ErrorCode::InvalidDisputeStatus)?;
+    let (buyer_amount, seller_amount) = distribute_escrow(
+        escrow_account,
+        offer,
+        buyer,
+        seller,
+        buyer_payout(offer, buyer_percentage)?,
+    )?;
+
+    // Emit event with actual transferred amounts
+    emit!(VerdictExecuted {
+        dispute: dispute.key(),
+        buyer_percentage,
+        buyer_amount,
+        seller_amount,
+    });
+
+    // Record the verdict on both parties' reputations
+    record_dispute_outcome(
+        &mut ctx.accounts.buyer_reputation,
+        &mut ctx.accounts.seller_reputation,
+        buyer_percentage as u64,
+        100 - buyer_percentage as u64,
+        offer.amount,
+    )?;
+
+    settle_appeal_bond(
+        dispute,
+        &ctx.accounts.dispute_escrow,
+        buyer,
+        seller,
+        Some(buyer_percentage),
+    )?;
+    settle_dispute_fees(
+        dispute,
+        &ctx.accounts.dispute_escrow,
+        buyer,
+        seller,
+        Some(buyer_percentage),
+    )?;
+    compensate_majority_jurors(
+        dispute,
+        &ctx.accounts.dispute_escrow,
+        &mut ctx.accounts.juror_registry,
+        &ctx.accounts.reward_token,
+        ctx.remaining_accounts,
+        clock.unix_timestamp,
+    )?;
+
+    // Update dispute and offer status
+    dispute.status = DisputeStatus::Resolved as u8;
+    dispute.resolved_at = clock.unix_timestamp;
+    offer.status = OfferStatus::Completed as u8;
+    offer.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+pub fn propose_force_resolve(
+    ctx: Context<ProposeForceResolve>,
+    buyer_percentage: u8,
+    justification_hash: [u8; 32],
+) -> Result<()> {
+    let dispute = &ctx.accounts.dispute;
+    let override_record = &mut ctx.accounts.override_record;
+    let clock = Clock::get()?;
+
+    let proposed_by = collect_admin_signers(
+        &ctx.accounts.admin,
+        &ctx.accounts.authority.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    // Validate dispute status
+    if dispute.status == DisputeStatus::Resolved as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if buyer_percentage > 100 {
+        return Err(error!(ErrorCode::InvalidAllocation));
+    }
+    if justification_hash == [0u8; 32] {
+        return Err(error!(ErrorCode::MissingJustification));
+    }
+
+    override_record.dispute = dispute.key();
+    override_record.buyer_percentage = buyer_percentage;
+    override_record.justification_hash = justification_hash;
+    override_record.proposed_by = proposed_by;
+    override_record.proposed_at = clock.unix_timestamp;
+    override_record.executed_by = Vec::new();
+    override_record.executed_at = 0;
+    override_record.bump = ctx.bumps.override_record;
+
+    // Emit event
+    emit!(ForceResolveProposed {
+        dispute: dispute.key(),
+        override_record: override_record.key(),
+        buyer_percentage,
+        justification_hash,
+        executable_at: clock.unix_timestamp
+            .checked_add(dispute.timings.override_timelock)
+            .ok_or(ErrorCode::MathOverflow)?,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_force_resolve(ctx: Context<CancelForceResolve>) -> Result<()> {
+    let override_record = &ctx.accounts.override_record;
+
+    let cancelled_by = collect_admin_signers(
+        &ctx.accounts.admin,
+        &ctx.accounts.authority.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    // Executed overrides are the permanent record of the resolution
+    if override_record.executed_at != 0 {
+        return Err(error!(ErrorCode::OverrideAlreadyExecuted));
+    }
+
+    // Emit event (the record is closed once the instruction completes)
+    emit!(ForceResolveCancelled {
+        dispute: ctx.accounts.dispute.key(),
+        override_record: override_record.key(),
+        cancelled_by,
+    });
+
+    Ok(())
+}
+
+pub fn force_resolve(ctx: Context<ForceResolve>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let override_record = &mut ctx.accounts.override_record;
+    let offer = &mut ctx.accounts.offer;
+    let escrow_account = &ctx.accounts.escrow_account;
+    let dispute_escrow = &ctx.accounts.dispute_escrow;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let clock = Clock::get()?;
+
+    let executed_by = collect_admin_signers(
+        &ctx.accounts.admin,
+        &ctx.accounts.authority.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    // Validate dispute status
+    if dispute.status == DisputeStatus::Resolved as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if override_record.executed_at != 0 {
+        return Err(error!(ErrorCode::OverrideAlreadyExecuted));
+    }
+
+    // Parties and jurors get the timelock to see the override coming
+    let executable_at = override_record.proposed_at
+        .checked_add(dispute.timings.override_timelock)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if clock.unix_timestamp < executable_at {
+        return Err(error!(ErrorCode::OverrideTimelockActive));
+    }
+
+    validate_dispute_parties(dispute, offer, buyer, seller)?;
+
+    let buyer_percentage = override_record.buyer_percentage;
+    let (buyer_amount, seller_amount) = distribute_escrow(
+        escrow_account,
+        offer,
+        buyer,
+        seller,
+        buyer_payout(offer, buyer_percentage)?,
+    )?;
+
+    // The panel's work is set aside: the appeal bond and both fees are refunded
+    settle_appeal_bond(dispute, dispute_escrow, buyer, seller, None)?;
+    settle_dispute_fees(dispute, dispute_escrow, buyer, seller, None)?;
+
+    // Record the override on both parties' reputations
+    record_dispute_outcome(
+        &mut ctx.accounts.buyer_reputation,
+        &mut ctx.accounts.seller_reputation,
+        buyer_percentage as u64,
+        100 - buyer_percentage as u64,
+        offer.amount,
+    )?;
+
+    // Seal the record
+    override_record.executed_by = executed_by;
+    override_record.executed_at = clock.unix_timestamp;
+
+    // Emit event
+    emit!(DisputeForceResolved {
+        dispute: dispute.key(),
+        override_record: override_record.key(),
+        buyer_percentage,
+        buyer_amount,
+        seller_amount,
+    });
+
+    // Update dispute and offer status
+    dispute.overridden = true;
+    dispute.status = DisputeStatus::Resolved as u8;
+    dispute.resolved_at = clock.unix_timestamp;
+    offer.status = OfferStatus::Completed as u8;
+    offer.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
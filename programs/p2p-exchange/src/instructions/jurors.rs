@@ -0,0 +1,922 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+use anchor_lang::system_program;
+use crate::state::{Admin, Dispute, DisputeStatus, JurorRegistry, JurorStake, JurorStatus, TradeHistory, Vote, MAX_REGISTERED_JURORS, MAX_VACATED_JURORS};
+use crate::state::{JurorRegistered, JurorUnregistered, JurorSlashed, JurorRegistrySwept, JurorsDrawn, JurorDrawRescheduled, JurorSeatVacated, JurorReplaced};
+use crate::errors::ErrorCode;
+use crate::utils::transfer_from_pda;
+
+#[derive(Accounts)]
+pub struct InitializeJurorRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + JurorRegistry::LEN,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterJuror<'info> {
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + JurorStake::LEN,
+        seeds = [JurorStake::SEED.as_bytes(), juror.key().as_ref()],
+        bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IncreaseJurorStake<'info> {
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        mut,
+        seeds = [JurorStake::SEED.as_bytes(), juror.key().as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnregisterJuror<'info> {
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        mut,
+        close = juror,
+        seeds = [JurorStake::SEED.as_bytes(), juror.key().as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    #[account(mut)]
+    pub juror: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetJurorSuspension<'info> {
+    #[account(
+        mut,
+        seeds = [JurorStake::SEED.as_bytes(), juror_stake.juror.as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepJurorRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        seeds = [Admin::SEED.as_bytes()],
+        bump,
+        constraint = admin.authority == authority.key() @ ErrorCode::AdminRequired
+    )]
+    pub admin: Account<'info, Admin>,
+    pub authority: Signer<'info>,
+    /// CHECK: Any account chosen by the admin to receive the forfeited lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleJuror<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        mut,
+        seeds = [JurorStake::SEED.as_bytes(), juror_stake.juror.as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    /// CHECK: Vote PDA of the juror; uninitialized if the juror never voted
+    #[account(
+        seeds = [b"vote", dispute.key().as_ref(), juror_stake.juror.as_ref()],
+        bump
+    )]
+    pub vote: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DrawJurors<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    /// CHECK: Trade history of the initiator; uninitialized if the user never created one
+    #[account(
+        seeds = [TradeHistory::SEED.as_bytes(), dispute.initiator.as_ref()],
+        bump
+    )]
+    pub initiator_trade_history: UncheckedAccount<'info>,
+    /// CHECK: Trade history of the respondent; uninitialized if the user never created one
+    #[account(
+        seeds = [TradeHistory::SEED.as_bytes(), dispute.respondent.as_ref()],
+        bump
+    )]
+    pub respondent_trade_history: UncheckedAccount<'info>,
+    /// CHECK: SlotHashes sysvar, validated by address
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecuseJuror<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [JurorStake::SEED.as_bytes(), juror.key().as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    /// CHECK: Vote PDA of the juror; must be uninitialized
+    #[account(
+        seeds = [b"vote", dispute.key().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub vote: UncheckedAccount<'info>,
+    pub juror: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReplaceInactiveJuror<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [JurorRegistry::SEED.as_bytes()],
+        bump = juror_registry.bump
+    )]
+    pub juror_registry: Account<'info, JurorRegistry>,
+    #[account(
+        mut,
+        seeds = [JurorStake::SEED.as_bytes(), juror_stake.juror.as_ref()],
+        bump = juror_stake.bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+    /// CHECK: Vote PDA of the inactive juror; must be uninitialized
+    #[account(
+        seeds = [b"vote", dispute.key().as_ref(), juror_stake.juror.as_ref()],
+        bump
+    )]
+    pub vote: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_juror_registry(ctx: Context<InitializeJurorRegistry>, min_stake: u64) -> Result<()> {
+    if min_stake == 0 {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    juror_registry.authority = ctx.accounts.authority.key();
+    juror_registry.min_stake = min_stake;
+    juror_registry.total_staked = 0;
+    juror_registry.forfeited_lamports = 0;
+    juror_registry.jurors = Vec::new();
+    juror_registry.bump = ctx.bumps.juror_registry;
+
+    Ok(())
+}
+
+pub fn register_juror(ctx: Context<RegisterJuror>, stake_amount: u64) -> Result<()> {
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+    let juror = &ctx.accounts.juror;
+    let clock = Clock::get()?;
+
+    // Validate stake
+    if stake_amount < juror_registry.min_stake {
+        return Err(error!(ErrorCode::InsufficientFunds));
+    }
+    if juror_registry.jurors.len() >= MAX_REGISTERED_JURORS {
+        return Err(error!(ErrorCode::JurorRegistryFull));
+    }
+
+    // Transfer stake into the juror stake PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: juror.to_account_info(),
+                to: juror_stake.to_account_info(),
+            },
+        ),
+        stake_amount,
+    )?;
+
+    // Initialize juror stake data
+    juror_stake.juror = juror.key();
+    juror_stake.stake = stake_amount;
+    juror_stake.status = JurorStatus::Active as u8;
+    juror_stake.active_disputes = 0;
+    juror_stake.disputes_served = 0;
+    juror_stake.missed_votes = 0;
+    juror_stake.minority_votes = 0;
+    juror_stake.consecutive_minority = 0;
+    juror_stake.slash_count = 0;
+    juror_stake.registered_at = clock.unix_timestamp;
+    juror_stake.bump = ctx.bumps.juror_stake;
+
+    juror_registry.jurors.push(juror.key());
+    juror_registry.total_staked = juror_registry.total_staked
+        .checked_add(stake_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Emit event
+    emit!(JurorRegistered {
+        juror: juror.key(),
+        stake: stake_amount,
+    });
+
+    Ok(())
+}
+
+pub fn increase_juror_stake(ctx: Context<IncreaseJurorStake>, amount: u64) -> Result<()> {
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+    let juror = &ctx.accounts.juror;
+
+    if amount == 0 {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: juror.to_account_info(),
+                to: juror_stake.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    juror_stake.stake = juror_stake.stake
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    juror_registry.total_staked = juror_registry.total_staked
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+pub fn unregister_juror(ctx: Context<UnregisterJuror>) -> Result<()> {
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let juror_stake = &ctx.accounts.juror_stake;
+
+    // Stake stays locked while the juror may still be slashed; suspended jurors can leave
+    if juror_stake.active_disputes > 0 {
+        return Err(error!(ErrorCode::JurorHasActiveDisputes));
+    }
+
+    if let Some(index) = juror_registry.jurors.iter().position(|key| *key == juror_stake.juror) {
+        juror_registry.jurors.swap_remove(index);
+    }
+    juror_registry.total_staked = juror_registry.total_staked.saturating_sub(juror_stake.stake);
+
+    // Emit event (stake and rent are returned when the account is closed)
+    emit!(JurorUnregistered {
+        juror: juror_stake.juror,
+        refunded_stake: juror_stake.stake,
+    });
+
+    Ok(())
+}
+
+pub fn set_juror_suspension(ctx: Context<SetJurorSuspension>, suspended: bool) -> Result<()> {
+    let juror_stake = &mut ctx.accounts.juror_stake;
+
+    juror_stake.status = if suspended {
+        JurorStatus::Suspended as u8
+    } else {
+        juror_stake.slash_count = 0;
+        JurorStatus::Active as u8
+    };
+
+    Ok(())
+}
+
+pub fn sweep_juror_registry(ctx: Context<SweepJurorRegistry>) -> Result<()> {
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let recipient = &ctx.accounts.recipient;
+
+    let amount = juror_registry.forfeited_lamports;
+    if amount == 0 {
+        return Err(error!(ErrorCode::InsufficientFunds));
+    }
+
+    // Only forfeited lamports leave the registry; its rent reserve stays
+    transfer_from_pda(
+        &juror_registry.to_account_info(),
+        &recipient.to_account_info(),
+        amount,
+    )?;
+    juror_registry.forfeited_lamports = 0;
+
+    // Emit event
+    emit!(JurorRegistrySwept {
+        recipient: recipient.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn settle_juror(ctx: Context<SettleJuror>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+    let vote_info = &ctx.accounts.vote;
+    let clock = Clock::get()?;
+
+    // Validate juror is assigned to this dispute
+    let seat = dispute.jurors.iter().position(|key| *key == juror_stake.juror)
+        .ok_or(ErrorCode::NotAJuror)?;
+    let seat_mask = 1u32 << seat;
+    if dispute.jurors_settled & seat_mask != 0 {
+        return Err(error!(ErrorCode::JurorAlreadySettled));
+    }
+
+    // Jurors are settled once the dispute is resolved
+    if dispute.status != DisputeStatus::Resolved as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+
+    // A negotiated settlement or admin override releases the panel without judging votes
+    if dispute.settled_by_parties || dispute.overridden {
+        juror_stake.active_disputes = juror_stake.active_disputes.saturating_sub(1);
+        dispute.jurors_settled |= seat_mask;
+        return Ok(());
+    }
+
+    // Otherwise votes are judged once the reveal window has closed
+    if clock.unix_timestamp <= dispute.reveal_ends_at() {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+
+    let vote = if vote_info.data_is_empty() {
+        None
+    } else {
+        if vote_info.owner != &crate::ID {
+            return Err(error!(ErrorCode::Unauthorized));
+        }
+        Some(Vote::try_deserialize(&mut &vote_info.try_borrow_data()?[..])?)
+    };
+
+    let slash_reason = match vote {
+        // Missed the voting deadline
+        None => {
+            juror_stake.missed_votes = juror_stake.missed_votes
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+            Some(JurorStake::SLASH_REASON_MISSED_VOTE)
+        }
+        // Committed but never revealed
+        Some(vote) if !vote.revealed => {
+            juror_stake.missed_votes = juror_stake.missed_votes
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+            Some(JurorStake::SLASH_REASON_UNREVEALED_VOTE)
+        }
+        Some(vote) => {
+            juror_stake.disputes_served = juror_stake.disputes_served
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            // Minority is judged against the verdict of the juror's own round
+            if dispute.is_minority_allocation(seat, vote.buyer_percentage) {
+                juror_stake.minority_votes = juror_stake.minority_votes
+                    .checked_add(1)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                juror_stake.consecutive_minority = juror_stake.consecutive_minority.saturating_add(1);
+
+                if juror_stake.consecutive_minority >= JurorStake::MINORITY_STRIKE_LIMIT {
+                    juror_stake.consecutive_minority = 0;
+                    Some(JurorStake::SLASH_REASON_MINORITY)
+                } else {
+                    None
+                }
+            } else {
+                juror_stake.consecutive_minority = 0;
+                None
+            }
+        }
+    };
+
+    if let Some(reason) = slash_reason {
+        slash_juror(
+            dispute.key(),
+            juror_registry,
+            juror_stake,
+            reason,
+        )?;
+    }
+
+    juror_stake.active_disputes = juror_stake.active_disputes.saturating_sub(1);
+    dispute.jurors_settled |= seat_mask;
+
+    Ok(())
+}
+
+pub fn draw_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJurors<'info>>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_registry = &ctx.accounts.juror_registry;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::Opened as u8 {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if !dispute.respondent_fee_paid {
+        return Err(error!(ErrorCode::RespondentFeeUnpaid));
+    }
+    if clock.slot <= dispute.draw_slot {
+        return Err(error!(ErrorCode::DrawSlotNotReached));
+    }
+
+    // The draw slot hash only stays in SlotHashes for ~512 slots; pick a new one if it aged out
+    let slot_hash = match find_slot_hash(&ctx.accounts.slot_hashes, dispute.draw_slot)? {
+        Some(slot_hash) => slot_hash,
+        None => {
+            dispute.draw_slot = clock.slot
+                .checked_add(Dispute::DRAW_DELAY_SLOTS)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            emit!(JurorDrawRescheduled {
+                dispute: dispute.key(),
+                draw_slot: dispute.draw_slot,
+            });
+
+            return Ok(());
+        }
+    };
+
+    // Parties, their recent counterparties and jurors of earlier rounds cannot judge the dispute
+    let mut excluded = conflicted_jurors(
+        dispute,
+        &ctx.accounts.initiator_trade_history,
+        &ctx.accounts.respondent_trade_history,
+    )?;
+
+    let draw_seed = hashv(&[&slot_hash, dispute.key().as_ref()]).to_bytes();
+    let jurors = draw_candidates(
+        juror_registry,
+        &draw_seed,
+        dispute.panel_size as usize,
+        &mut excluded,
+        ctx.remaining_accounts,
+    )?;
+
+    // Assign jurors and record the draw for auditability
+    dispute.jurors.extend(jurors);
+    dispute.draw_seed = draw_seed;
+    dispute.start_phase_clock(clock.unix_timestamp);
+    dispute.status = DisputeStatus::JurorsAssigned as u8;
+
+    // Emit event
+    emit!(JurorsDrawn {
+        dispute: dispute.key(),
+        jurors: dispute.current_panel().to_vec(),
+        draw_slot: dispute.draw_slot,
+        draw_seed,
+    });
+
+    Ok(())
+}
+
+pub fn recuse_juror(ctx: Context<RecuseJuror>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+    let juror = &ctx.accounts.juror;
+    let clock = Clock::get()?;
+
+    let seat = validate_replaceable_seat(dispute, &juror.key(), &ctx.accounts.vote, clock.unix_timestamp)?;
+    vacate_seat(dispute, seat, clock.slot)?;
+
+    // Recusal is voluntary, so the stake is released without penalty
+    juror_stake.active_disputes = juror_stake.active_disputes.saturating_sub(1);
+
+    // Emit event
+    emit!(JurorSeatVacated {
+        dispute: dispute.key(),
+        seat: seat as u8,
+        previous_juror: juror.key(),
+        recused: true,
+        replacement_draw_slot: dispute.replacement_draw_slot,
+    });
+
+    Ok(())
+}
+
+pub fn replace_inactive_juror(ctx: Context<ReplaceInactiveJuror>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_registry = &mut ctx.accounts.juror_registry;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+    let clock = Clock::get()?;
+    let previous_juror = juror_stake.juror;
+
+    let seat = validate_replaceable_seat(dispute, &previous_juror, &ctx.accounts.vote, clock.unix_timestamp)?;
+
+    // Every juror gets a full inactivity window of commit phase from their own seating
+    let inactive_after = dispute.commit_eligible_since(seat)
+        .checked_add(dispute.timings.inactivity_window)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if clock.unix_timestamp <= inactive_after {
+        return Err(error!(ErrorCode::JurorNotInactive));
+    }
+
+    vacate_seat(dispute, seat, clock.slot)?;
+
+    // The replaced juror leaves the panel now, so the missed vote is settled here
+    juror_stake.missed_votes = juror_stake.missed_votes
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    slash_juror(
+        dispute.key(),
+        juror_registry,
+        juror_stake,
+        JurorStake::SLASH_REASON_INACTIVE,
+    )?;
+    juror_stake.active_disputes = juror_stake.active_disputes.saturating_sub(1);
+
+    // Emit event
+    emit!(JurorSeatVacated {
+        dispute: dispute.key(),
+        seat: seat as u8,
+        previous_juror,
+        recused: false,
+        replacement_draw_slot: dispute.replacement_draw_slot,
+    });
+
+    Ok(())
+}
+
+pub fn draw_replacement_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJurors<'info>>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_registry = &ctx.accounts.juror_registry;
+    let clock = Clock::get()?;
+
+    // Validate dispute status
+    if dispute.status != DisputeStatus::JurorsAssigned as u8
+        && dispute.status != DisputeStatus::EvidenceSubmission as u8
+        && dispute.status != DisputeStatus::Voting as u8
+    {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if dispute.vacant_seats == 0 {
+        return Err(error!(ErrorCode::NoVacantSeats));
+    }
+    if clock.slot <= dispute.replacement_draw_slot {
+        return Err(error!(ErrorCode::DrawSlotNotReached));
+    }
+
+    // The draw slot hash only stays in SlotHashes for ~512 slots; pick a new one if it aged out
+    let slot_hash = match find_slot_hash(&ctx.accounts.slot_hashes, dispute.replacement_draw_slot)? {
+        Some(slot_hash) => slot_hash,
+        None => {
+            dispute.replacement_draw_slot = clock.slot
+                .checked_add(Dispute::DRAW_DELAY_SLOTS)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            emit!(JurorDrawRescheduled {
+                dispute: dispute.key(),
+                draw_slot: dispute.replacement_draw_slot,
+            });
+
+            return Ok(());
+        }
+    };
+
+    // Conflicts include the whole panel and every juror who ever vacated a seat, so no juror is drawn twice
+    let mut excluded = conflicted_jurors(
+        dispute,
+        &ctx.accounts.initiator_trade_history,
+        &ctx.accounts.respondent_trade_history,
+    )?;
+
+    let vacant_seats: Vec<usize> = (0..dispute.current_panel().len())
+        .filter(|seat| dispute.vacant_seats & (1 << seat) != 0)
+        .collect();
+    let draw_seed = hashv(&[&slot_hash, dispute.key().as_ref(), &dispute.replacement_draw_slot.to_le_bytes()]).to_bytes();
+    let replacements = draw_candidates(
+        juror_registry,
+        &draw_seed,
+        vacant_seats.len(),
+        &mut excluded,
+        ctx.remaining_accounts,
+    )?;
+
+    // Seat the replacements; each gets its own inactivity window to commit
+    let dispute_key = dispute.key();
+    for (seat, replacement) in vacant_seats.into_iter().zip(replacements) {
+        let index = dispute.panel_start as usize + seat;
+        dispute.jurors[index] = replacement;
+        dispute.jurors_settled &= !(1u32 << index);
+        dispute.seated_at[seat] = clock.unix_timestamp;
+
+        emit!(JurorReplaced {
+            dispute: dispute_key,
+            seat: seat as u8,
+            new_juror: replacement,
+            draw_seed,
+        });
+    }
+    dispute.vacant_seats = 0;
+    dispute.replacement_draw_slot = 0;
+    dispute.extend_voting_for_replacement(clock.unix_timestamp);
+
+    Ok(())
+}
+
+// Helper function to look up the hash of a slot in the SlotHashes sysvar
+fn find_slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    const ENTRY_LEN: usize = 8 + 32; // slot + hash
+
+    let data = slot_hashes.try_borrow_data()?;
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+    let mut len_bytes = [0u8; 8];
+    len_bytes.copy_from_slice(&data[..8]);
+    let entries = u64::from_le_bytes(len_bytes) as usize;
+
+    // Entries are sorted by slot, newest first
+    for entry in data[8..].chunks_exact(ENTRY_LEN).take(entries) {
+        let mut slot_bytes = [0u8; 8];
+        slot_bytes.copy_from_slice(&entry[..8]);
+        let entry_slot = u64::from_le_bytes(slot_bytes);
+
+        if entry_slot == slot {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&entry[8..]);
+            return Ok(Some(hash));
+        }
+        if entry_slot < slot {
+            break;
+        }
+    }
+
+    Ok(None)
+}
+
+// Helper function to list everyone with a conflict of interest in a dispute: the parties,
+// their recent counterparties, jurors of earlier rounds and jurors who vacated a seat
+pub(crate) fn conflicted_jurors(
+    dispute: &Dispute,
+    initiator_trade_history: &AccountInfo,
+    respondent_trade_history: &AccountInfo,
+) -> Result<Vec<Pubkey>> {
+    let mut excluded = vec![dispute.initiator, dispute.respondent];
+    excluded.extend(dispute.jurors.iter().copied());
+    excluded.extend(dispute.vacated_jurors.iter().copied());
+    excluded.extend(load_recent_counterparties(initiator_trade_history)?);
+    excluded.extend(load_recent_counterparties(respondent_trade_history)?);
+    Ok(excluded)
+}
+
+// Helper function to read a user's recent counterparties (empty if no history exists)
+fn load_recent_counterparties(trade_history: &AccountInfo) -> Result<Vec<Pubkey>> {
+    if trade_history.data_is_empty() {
+        return Ok(Vec::new());
+    }
+    if trade_history.owner != &crate::ID {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    let trade_history = TradeHistory::try_deserialize(&mut &trade_history.try_borrow_data()?[..])?;
+    Ok(trade_history.recent_counterparties
+        .iter()
+        .filter(|key| **key != Pubkey::default())
+        .copied()
+        .collect())
+}
+
+// Helper function to check eligibility and lock a juror's stake for a dispute
+pub(crate) fn reserve_juror(
+    juror_registry: &JurorRegistry,
+    juror: &Pubkey,
+    juror_stake: &mut JurorStake,
+) -> Result<()> {
+    if juror_stake.juror != *juror
+        || *juror == Pubkey::default()
+        || !juror_registry.jurors.contains(juror)
+        || juror_stake.status != JurorStatus::Active as u8
+        || juror_stake.stake < juror_registry.min_stake
+    {
+        return Err(error!(ErrorCode::JurorNotEligible));
+    }
+
+    juror_stake.active_disputes = juror_stake.active_disputes
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+// Helper function to draw `count` eligible jurors, reserving each one's stake
+fn draw_candidates<'info>(
+    juror_registry: &JurorRegistry,
+    draw_seed: &[u8; 32],
+    count: usize,
+    excluded: &mut Vec<Pubkey>,
+    juror_stakes: &'info [AccountInfo<'info>],
+) -> Result<Vec<Pubkey>> {
+    if juror_registry.jurors.is_empty() {
+        return Err(error!(ErrorCode::NotEnoughEligibleJurors));
+    }
+
+    let mut juror_stakes = juror_stakes.iter();
+    let mut jurors: Vec<Pubkey> = Vec::with_capacity(count);
+
+    for attempt in 0..Dispute::MAX_DRAW_ATTEMPTS {
+        if jurors.len() == count {
+            break;
+        }
+
+        let candidate_hash = hashv(&[draw_seed, &[attempt]]).to_bytes();
+        let mut index_bytes = [0u8; 8];
+        index_bytes.copy_from_slice(&candidate_hash[..8]);
+        let index = u64::from_le_bytes(index_bytes) % juror_registry.jurors.len() as u64;
+        let candidate = juror_registry.jurors[index as usize];
+
+        if excluded.contains(&candidate) || jurors.contains(&candidate) {
+            continue;
+        }
+
+        // Every examined candidate must have its stake supplied, in draw order
+        let stake_info = juror_stakes.next().ok_or(ErrorCode::InvalidJurorStakeAccount)?;
+        if !stake_info.is_writable {
+            return Err(error!(ErrorCode::InvalidJurorStakeAccount));
+        }
+        let mut juror_stake = Account::<JurorStake>::try_from(stake_info)?;
+        if juror_stake.juror != candidate {
+            return Err(error!(ErrorCode::InvalidJurorStakeAccount));
+        }
+
+        // Ineligible candidates are skipped for the rest of the draw
+        if reserve_juror(juror_registry, &candidate, &mut juror_stake).is_err() {
+            excluded.push(candidate);
+            continue;
+        }
+        juror_stake.exit(&crate::ID)?;
+        jurors.push(candidate);
+    }
+
+    if jurors.len() < count {
+        return Err(error!(ErrorCode::NotEnoughEligibleJurors));
+    }
+
+    Ok(jurors)
+}
+
+// Helper function to check that a seated juror can still be swapped out before committing,
+// returning the juror's seat on the current panel
+fn validate_replaceable_seat(dispute: &Dispute, juror: &Pubkey, vote: &AccountInfo, now: i64) -> Result<usize> {
+    if dispute.status != DisputeStatus::JurorsAssigned as u8
+        && dispute.status != DisputeStatus::EvidenceSubmission as u8
+        && dispute.status != DisputeStatus::Voting as u8
+    {
+        return Err(error!(ErrorCode::InvalidDisputeStatus));
+    }
+    if now > dispute.voting_ends_at {
+        return Err(error!(ErrorCode::DisputeExpired));
+    }
+    let seat = dispute.seat_of(juror).ok_or(ErrorCode::NotAJuror)?;
+
+    // A committed vote stays bound to its seat
+    if !vote.data_is_empty() {
+        return Err(error!(ErrorCode::AlreadyVoted));
+    }
+
+    Ok(seat)
+}
+
+// Helper function to vacate a seat on the current panel and schedule its replacement draw
+// on the hash of a future slot, so nobody can predict the replacement when vacating
+fn vacate_seat(dispute: &mut Dispute, seat: usize, current_slot: u64) -> Result<()> {
+    // The leaving juror is remembered, so no later draw for this dispute can seat them again
+    let index = dispute.panel_start as usize + seat;
+    if dispute.vacated_jurors.len() >= MAX_VACATED_JURORS {
+        return Err(error!(ErrorCode::VacancyLimitReached));
+    }
+    let juror = dispute.jurors[index];
+    dispute.vacated_jurors.push(juror);
+
+    // The leaving juror is settled now; the seat's settlement bit is reset once it is refilled
+    dispute.vacant_seats |= 1 << seat;
+    dispute.jurors_settled |= 1u32 << index;
+    dispute.extension_approvals &= !(1 << seat);
+
+    // A draw slot that already passed has a known hash, so it is pushed into the future
+    if dispute.replacement_draw_slot <= current_slot {
+        dispute.replacement_draw_slot = current_slot
+            .checked_add(Dispute::DRAW_DELAY_SLOTS)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    Ok(())
+}
+
+// Helper function to slash a juror's stake into the registry
+fn slash_juror<'info>(
+    dispute: Pubkey,
+    juror_registry: &mut Account<'info, JurorRegistry>,
+    juror_stake: &mut Account<'info, JurorStake>,
+    reason: &str,
+) -> Result<()> {
+    let slash_amount = juror_stake.stake
+        .checked_mul(JurorStake::SLASH_BASIS_POINTS)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Move slashed stake into the registry
+    let juror_key = juror_stake.juror;
+    transfer_from_pda(
+        &juror_stake.to_account_info(),
+        &juror_registry.to_account_info(),
+        slash_amount,
+    )?;
+
+    juror_stake.stake = juror_stake.stake
+        .checked_sub(slash_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    juror_stake.slash_count = juror_stake.slash_count.saturating_add(1);
+    if juror_stake.slash_count >= JurorStake::MAX_SLASHES {
+        juror_stake.status = JurorStatus::Suspended as u8;
+    }
+
+    juror_registry.total_staked = juror_registry.total_staked.saturating_sub(slash_amount);
+    juror_registry.forfeited_lamports = juror_registry.forfeited_lamports
+        .checked_add(slash_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Emit event
+    emit!(JurorSlashed {
+        juror: juror_key,
+        dispute,
+        amount: slash_amount,
+        reason: reason.to_string(),
+        suspended: juror_stake.status == JurorStatus::Suspended as u8,
+    });
+
+    Ok(())
+}
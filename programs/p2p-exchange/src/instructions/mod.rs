@@ -1,11 +1,15 @@
 pub mod admin;
 pub mod offers;
+pub mod batch;
 pub mod disputes;
+pub mod jurors;
 pub mod reputation;
 pub mod rewards;
 
 pub use admin::*;
 pub use offers::*;
+pub use batch::*;
 pub use disputes::*;
+pub use jurors::*;
 pub use reputation::*;
 pub use rewards::*;
\ No newline at end of file
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{program::invoke, program::invoke_signed, system_instruction, sysvar::rent::Rent};
-use crate::state::{EscrowAccount, Offer, OfferStatus, Reputation, MAX_FIAT_CURRENCY_LEN, MAX_PAYMENT_METHOD_LEN};
-use crate::state::{OfferCreated, OfferAccepted, FiatSent, FiatReceiptConfirmed, SolReleased, RewardEligible};
+use anchor_lang::solana_program::sysvar::rent::Rent;
+use anchor_lang::system_program;
+use crate::state::{CooldownConfig, EscrowAccount, Offer, OfferStatus, OrderBookPage, Reputation, TradeHistory, MAX_FIAT_CURRENCY_LEN, MAX_PAYMENT_METHOD_LEN, ORDER_BOOK_PAGE_CAPACITY};
+use crate::state::{OfferCreated, OfferUpdated, OfferListed, OfferUnlisted, OfferCancelled, OfferAccepted, FiatSent, FiatReceiptConfirmed, SolReleased, RewardEligible};
 use crate::errors::ErrorCode;
-use crate::utils::validate_and_process_string;
+use crate::utils::{transfer_from_escrow, validate_and_process_string, validate_currency_code};
+use crate::instructions::reputation::{record_counterparty, record_trade_outcome, TradeOutcome};
 
 // Remove the duplicated validate_and_trim_string function - now using common utility
 
@@ -21,17 +23,92 @@ pub struct CreateOffer<'info> {
         bump
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub reputation: Account<'info, Reputation>,
+    #[account(
+        seeds = [CooldownConfig::SEED.as_bytes()],
+        bump = cooldown_config.bump
+    )]
+    pub cooldown_config: Account<'info, CooldownConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(fiat_currency: String, page: u32)]
+pub struct CreateOrderBookPage<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OrderBookPage::LEN,
+        seeds = [OrderBookPage::SEED.as_bytes(), fiat_currency.as_bytes(), &page.to_le_bytes()],
+        bump
+    )]
+    pub order_book_page: Account<'info, OrderBookPage>,
+    /// Required for every page after the first so pages stay contiguous
+    pub previous_page: Option<Account<'info, OrderBookPage>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
+#[instruction(page: u32)]
 pub struct ListOffer<'info> {
     #[account(mut)]
     pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [OrderBookPage::SEED.as_bytes(), offer.fiat_currency.as_bytes(), &page.to_le_bytes()],
+        bump = order_book_page.bump
+    )]
+    pub order_book_page: Account<'info, OrderBookPage>,
+    #[account(mut, constraint = offer.seller == seller.key())]
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateOffer<'info> {
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(mut, constraint = offer.seller == seller.key())]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlistOffer<'info> {
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(mut)]
+    pub order_book_page: Account<'info, OrderBookPage>,
     #[account(mut, constraint = offer.seller == seller.key())]
     pub seller: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub offer: Account<'info, Offer>,
+    #[account(mut, constraint = offer.seller == seller.key())]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EscrowAccount::SEED.as_bytes(), offer.key().as_ref()],
+        bump = escrow_account.bump
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+    /// Only required when the offer is currently listed
+    #[account(mut)]
+    pub order_book_page: Option<Account<'info, OrderBookPage>>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct AcceptOffer<'info> {
     #[account(mut)]
@@ -44,6 +121,12 @@ pub struct AcceptOffer<'info> {
         bump = escrow_account.bump
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
+    /// Page listing the offer; accepted offers leave the order book
+    #[account(mut)]
+    pub order_book_page: Account<'info, OrderBookPage>,
+    /// Every trading party needs a reputation, so the trade can be recorded on it
+    #[account(seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
     pub system_program: Program<'info, System>,
 }
 
@@ -78,6 +161,36 @@ pub struct ReleaseSol<'info> {
         bump = escrow_account.bump
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
+    /// CHECK: Trade history of the seller; uninitialized if the user never created one
+    #[account(
+        mut,
+        seeds = [TradeHistory::SEED.as_bytes(), seller.key().as_ref()],
+        bump
+    )]
+    pub seller_trade_history: UncheckedAccount<'info>,
+    /// CHECK: Trade history of the buyer; uninitialized if the user never created one
+    #[account(
+        mut,
+        seeds = [TradeHistory::SEED.as_bytes(), buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_trade_history: UncheckedAccount<'info>,
+    /// Reputation of the seller
+    #[account(mut, seeds = [b"reputation", seller.key().as_ref()], bump)]
+    pub seller_reputation: Account<'info, Reputation>,
+    /// Reputation of the buyer
+    #[account(mut, seeds = [b"reputation", buyer.key().as_ref()], bump)]
+    pub buyer_reputation: Account<'info, Reputation>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateOffer<'info> {
+    /// CHECK: Offer in an older layout; resized and validated by hand
+    #[account(mut)]
+    pub offer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -101,14 +214,7 @@ pub fn create_offer(
     }
 
     // Enhanced currency code validation - ensure proper ISO format
-    if fiat_currency.len() < 3 || fiat_currency.len() > 3 {
-        return Err(error!(ErrorCode::InvalidCurrencyCode));
-    }
-    
-    // Check that currency code contains only uppercase letters
-    if !fiat_currency.chars().all(|c| c.is_ascii_uppercase()) {
-        return Err(error!(ErrorCode::InvalidCurrencyCode));
-    }
+    validate_currency_code(&fiat_currency)?;
 
     // Validate amount
     if amount == 0 || fiat_amount == 0 {
@@ -118,6 +224,17 @@ pub fn create_offer(
     let offer = &mut ctx.accounts.offer;
     let seller = &ctx.accounts.seller;
     let escrow_account = &mut ctx.accounts.escrow_account;
+    let reputation = &mut ctx.accounts.reputation;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Rate limit offer creation, scaled by reputation tier
+    let next_offer_at = reputation.last_offer_created
+        .checked_add(reputation.scaled_cooldown(ctx.accounts.cooldown_config.offer_creation_cooldown))
+        .ok_or(ErrorCode::MathOverflow)?;
+    if now < next_offer_at {
+        return Err(error!(ErrorCode::TooManyRequests));
+    }
+    reputation.last_offer_created = now;
 
     // Initialize escrow account
     escrow_account.offer = offer.key();
@@ -135,21 +252,19 @@ pub fn create_offer(
     offer.created_at = created_at;
     offer.updated_at = created_at;
     offer.dispute_id = None;
+    offer.order_book_page = None; // Set when listed
+    offer.terms_version = 0;
 
-    // Transfer SOL to escrow account using regular invoke (user-to-escrow)
-    let transfer_instruction = system_instruction::transfer(
-        &seller.key(),
-        &escrow_account.key(),
-        amount,
-    );
-    
-    invoke(
-        &transfer_instruction,
-        &[
-            seller.to_account_info(),
-            escrow_account.to_account_info(),
+    // Transfer SOL to escrow account through the System program (user-to-escrow)
+    system_program::transfer(
+        CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
-        ],
+            system_program::Transfer {
+                from: seller.to_account_info(),
+                to: escrow_account.to_account_info(),
+            },
+        ),
+        amount,
     )?;
 
     // Emit event
@@ -164,23 +279,158 @@ pub fn create_offer(
     Ok(())
 }
 
-pub fn list_offer(ctx: Context<ListOffer>) -> Result<()> {
+pub fn create_order_book_page(
+    ctx: Context<CreateOrderBookPage>,
+    fiat_currency: String,
+    page: u32,
+) -> Result<()> {
+    // Page seeds are derived from the raw input, so it must already be canonical
+    validate_currency_code(&fiat_currency)?;
+
+    // Pages must be created in order so clients can enumerate 0..n until the first gap
+    if page > 0 {
+        let previous_page = ctx.accounts.previous_page.as_ref()
+            .ok_or(ErrorCode::InvalidOrderBookPage)?;
+        if previous_page.fiat_currency != fiat_currency || previous_page.page != page - 1 {
+            return Err(error!(ErrorCode::InvalidOrderBookPage));
+        }
+    }
+
+    let order_book_page = &mut ctx.accounts.order_book_page;
+    order_book_page.fiat_currency = fiat_currency;
+    order_book_page.page = page;
+    order_book_page.offers = Vec::new();
+    order_book_page.bump = ctx.bumps.order_book_page;
+
+    Ok(())
+}
+
+pub fn list_offer(ctx: Context<ListOffer>, _page: u32) -> Result<()> {
     let offer = &mut ctx.accounts.offer;
+    let order_book_page = &mut ctx.accounts.order_book_page;
     let clock = Clock::get()?;
 
-    // Validate offer status
-    if offer.status != OfferStatus::Created as u8 {
+    list_offer_in_order_book(offer.key(), offer, order_book_page, clock.unix_timestamp)
+}
+
+pub fn update_offer(
+    ctx: Context<UpdateOffer>,
+    amount: u64,
+    fiat_amount: u64,
+    payment_method: String,
+) -> Result<()> {
+    // Input validation and sanitization
+    let payment_method = validate_and_process_string(&payment_method, MAX_PAYMENT_METHOD_LEN)?;
+
+    // Validate amount
+    if amount == 0 || fiat_amount == 0 {
+        return Err(error!(ErrorCode::InvalidAmount));
+    }
+
+    let offer = &mut ctx.accounts.offer;
+    let seller = &ctx.accounts.seller;
+    let escrow_account = &ctx.accounts.escrow_account;
+    let clock = Clock::get()?;
+
+    // Terms can only change before the offer is accepted
+    if offer.status != OfferStatus::Created as u8
+        && offer.status != OfferStatus::Listed as u8
+        && offer.status != OfferStatus::Unlisted as u8
+    {
         return Err(error!(ErrorCode::InvalidOfferStatus));
     }
 
-    // Update offer status
-    offer.status = OfferStatus::Listed as u8;
+    // Escrow must hold exactly the current amount before it is adjusted
+    let escrow_balance = escrow_account.to_account_info().lamports();
+    let minimum_rent_exempt = Rent::get()?.minimum_balance(EscrowAccount::LEN + 8);
+    let expected_balance = offer.amount
+        .checked_add(minimum_rent_exempt)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if escrow_balance != expected_balance {
+        return Err(error!(ErrorCode::InvalidEscrowBalance));
+    }
+
+    if amount > offer.amount {
+        // Top up escrow from the seller
+        let top_up = amount
+            .checked_sub(offer.amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: seller.to_account_info(),
+                    to: escrow_account.to_account_info(),
+                },
+            ),
+            top_up,
+        )?;
+    } else if amount < offer.amount {
+        // Return the difference to the seller
+        let withdrawal = offer.amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        transfer_from_escrow(
+            escrow_account,
+            &seller.to_account_info(),
+            withdrawal,
+        )?;
+    }
+
+    // Update offer terms
+    offer.amount = amount;
+    offer.fiat_amount = fiat_amount;
+    offer.payment_method = payment_method.clone();
+    offer.terms_version = offer.terms_version
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
     offer.updated_at = clock.unix_timestamp;
 
+    // Emit event
+    emit!(OfferUpdated {
+        offer: offer.key(),
+        seller: seller.key(),
+        amount,
+        fiat_amount,
+        payment_method,
+        terms_version: offer.terms_version,
+    });
+
     Ok(())
 }
 
-pub fn accept_offer(ctx: Context<AcceptOffer>, security_bond: u64) -> Result<()> {
+pub fn unlist_offer(ctx: Context<UnlistOffer>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    let order_book_page = &mut ctx.accounts.order_book_page;
+    let clock = Clock::get()?;
+
+    delist_offer_from_order_book(offer.key(), offer, order_book_page, clock.unix_timestamp)
+}
+
+pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    let clock = Clock::get()?;
+
+    cancel_offer_and_refund(
+        offer.key(),
+        offer,
+        &ctx.accounts.escrow_account,
+        ctx.accounts.order_book_page.as_deref_mut(),
+        &ctx.accounts.seller.to_account_info(),
+        clock.unix_timestamp,
+    )
+}
+
+pub fn accept_offer(
+    ctx: Context<AcceptOffer>,
+    security_bond: u64,
+    expected_amount: u64,
+    expected_fiat_amount: u64,
+    expected_terms_version: u32,
+) -> Result<()> {
     let offer = &mut ctx.accounts.offer;
     let buyer = &ctx.accounts.buyer;
     let escrow_account = &ctx.accounts.escrow_account;
@@ -191,27 +441,34 @@ pub fn accept_offer(ctx: Context<AcceptOffer>, security_bond: u64) -> Result<()>
         return Err(error!(ErrorCode::InvalidOfferStatus));
     }
 
+    // Front-running protection: the buyer accepts exactly the terms they saw
+    if offer.amount != expected_amount
+        || offer.fiat_amount != expected_fiat_amount
+        || offer.terms_version != expected_terms_version
+    {
+        return Err(error!(ErrorCode::OfferTermsChanged));
+    }
+
+    // Accepted offers can no longer be taken, so they leave the order book
+    remove_from_order_book(offer.key(), offer, &mut ctx.accounts.order_book_page)?;
+
     // Update offer data
     offer.buyer = Some(buyer.key());
     offer.security_bond = security_bond;
     offer.status = OfferStatus::Accepted as u8;
     offer.updated_at = clock.unix_timestamp;
 
-    // Transfer security bond to escrow account using regular invoke (user-to-escrow)
+    // Transfer security bond to escrow account through the System program (user-to-escrow)
     if security_bond > 0 {
-        let transfer_instruction = system_instruction::transfer(
-            &buyer.key(),
-            &escrow_account.key(),
-            security_bond,
-        );
-        
-        invoke(
-            &transfer_instruction,
-            &[
-                buyer.to_account_info(),
-                escrow_account.to_account_info(),
+        system_program::transfer(
+            CpiContext::new(
                 ctx.accounts.system_program.to_account_info(),
-            ],
+                system_program::Transfer {
+                    from: buyer.to_account_info(),
+                    to: escrow_account.to_account_info(),
+                },
+            ),
+            security_bond,
         )?;
     }
 
@@ -320,29 +577,15 @@ pub fn release_sol(ctx: Context<ReleaseSol>) -> Result<()> {
         .checked_sub(minimum_rent_exempt)
         .ok_or(ErrorCode::MathOverflow)?;
     
-    if transferable_amount > 0 {
-        let transfer_instruction = system_instruction::transfer(
-            &escrow_account.key(),
-            &buyer.key(),
-            transferable_amount,
-        );
-
-        let escrow_seeds = &[
-            EscrowAccount::SEED.as_bytes(),
-            &offer.key().to_bytes(),
-            &[escrow_account.bump],
-        ];
-
-        invoke_signed(
-            &transfer_instruction,
-            &[
-                escrow_account.to_account_info(),
-                buyer.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            &[escrow_seeds],
-        )?;
-    }
+    transfer_from_escrow(escrow_account, &buyer.to_account_info(), transferable_amount)?;
+
+    // Remember the counterparties so they can be excluded from each other's disputes
+    record_counterparty(&ctx.accounts.seller_trade_history, buyer.key())?;
+    record_counterparty(&ctx.accounts.buyer_trade_history, offer.seller)?;
+
+    // Both parties completed a trade
+    record_trade_outcome(&mut ctx.accounts.seller_reputation, TradeOutcome::Completed, offer.amount)?;
+    record_trade_outcome(&mut ctx.accounts.buyer_reputation, TradeOutcome::Completed, offer.amount)?;
 
     // Update offer status
     offer.status = OfferStatus::Completed as u8;
@@ -365,6 +608,204 @@ pub fn release_sol(ctx: Context<ReleaseSol>) -> Result<()> {
     Ok(())
 }
 
+pub fn migrate_offer(ctx: Context<MigrateOffer>) -> Result<()> {
+    let offer_info = ctx.accounts.offer.to_account_info();
+    let seller = &ctx.accounts.seller;
+    let new_len = 8 + Offer::LEN;
+
+    // Only offers created in an older, shorter layout need migrating
+    if offer_info.owner != &crate::ID {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+    if offer_info.data_len() >= new_len {
+        return Err(error!(ErrorCode::OfferAlreadyMigrated));
+    }
+    if !offer_info.try_borrow_data()?.starts_with(Offer::DISCRIMINATOR) {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch));
+    }
+
+    // The seller pays the rent for the larger account
+    let minimum_balance = Rent::get()?.minimum_balance(new_len);
+    let shortfall = minimum_balance.saturating_sub(offer_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: seller.to_account_info(),
+                    to: offer_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    // New fields are appended and zero-filled, so the old record reads as the new layout
+    offer_info.resize(new_len)?;
+    let mut offer = Offer::try_deserialize(&mut &offer_info.try_borrow_data()?[..])?;
+    if offer.seller != seller.key() {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    // Offers listed before the order book index are in no page, so they go back to
+    // Created and get relisted; later layouts already know their page
+    if offer.status == OfferStatus::Listed as u8 && offer.order_book_page.is_none() {
+        offer.status = OfferStatus::Created as u8;
+        offer.updated_at = Clock::get()?.unix_timestamp;
+    }
+    offer.try_serialize(&mut &mut offer_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+// Helper function shared by single and batch listing
+pub(crate) fn list_offer_in_order_book(
+    offer_key: Pubkey,
+    offer: &mut Offer,
+    order_book_page: &mut OrderBookPage,
+    now: i64,
+) -> Result<()> {
+    // Validate offer status (unlisted offers can be relisted)
+    if offer.status != OfferStatus::Created as u8 && offer.status != OfferStatus::Unlisted as u8 {
+        return Err(error!(ErrorCode::InvalidOfferStatus));
+    }
+
+    // Insert into the order book for the offer's currency
+    insert_into_order_book(offer_key, offer, order_book_page)?;
+
+    // Update offer status
+    offer.status = OfferStatus::Listed as u8;
+    offer.updated_at = now;
+
+    // Emit event
+    emit!(OfferListed {
+        offer: offer_key,
+        fiat_currency: offer.fiat_currency.clone(),
+        page: order_book_page.page,
+    });
+
+    Ok(())
+}
+
+// Helper function shared by single and batch delisting
+pub(crate) fn delist_offer_from_order_book(
+    offer_key: Pubkey,
+    offer: &mut Offer,
+    order_book_page: &mut OrderBookPage,
+    now: i64,
+) -> Result<()> {
+    // Only listed offers can be delisted
+    if offer.status != OfferStatus::Listed as u8 {
+        return Err(error!(ErrorCode::InvalidOfferStatus));
+    }
+
+    remove_from_order_book(offer_key, offer, order_book_page)?;
+
+    // Escrow stays in place; the offer simply stops being public
+    offer.status = OfferStatus::Unlisted as u8;
+    offer.updated_at = now;
+
+    // Emit event
+    emit!(OfferUnlisted {
+        offer: offer_key,
+        seller: offer.seller,
+    });
+
+    Ok(())
+}
+
+// Helper function shared by single and batch cancellation
+pub(crate) fn cancel_offer_and_refund<'info>(
+    offer_key: Pubkey,
+    offer: &mut Offer,
+    escrow_account: &Account<'info, EscrowAccount>,
+    order_book_page: Option<&mut OrderBookPage>,
+    seller: &AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    // Only offers that have not been accepted can be cancelled
+    if offer.status != OfferStatus::Created as u8
+        && offer.status != OfferStatus::Listed as u8
+        && offer.status != OfferStatus::Unlisted as u8
+    {
+        return Err(error!(ErrorCode::InvalidOfferStatus));
+    }
+
+    // Remove from the order book if listed
+    if offer.order_book_page.is_some() {
+        let order_book_page = order_book_page.ok_or(ErrorCode::InvalidOrderBookPage)?;
+        remove_from_order_book(offer_key, offer, order_book_page)?;
+    }
+
+    // Refund escrowed SOL to the seller (rent exempt amount stays in escrow)
+    let escrow_balance = escrow_account.to_account_info().lamports();
+    let minimum_rent_exempt = Rent::get()?.minimum_balance(EscrowAccount::LEN + 8);
+    let expected_balance = offer.amount
+        .checked_add(minimum_rent_exempt)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if escrow_balance != expected_balance {
+        return Err(error!(ErrorCode::InvalidEscrowBalance));
+    }
+
+    transfer_from_escrow(escrow_account, seller, offer.amount)?;
+
+    // Update offer status
+    offer.status = OfferStatus::Cancelled as u8;
+    offer.updated_at = now;
+
+    // Emit event
+    emit!(OfferCancelled {
+        offer: offer_key,
+        seller: seller.key(),
+        refunded_amount: offer.amount,
+    });
+
+    Ok(())
+}
+
+// Helper function to add a listed offer to an order book page
+pub(crate) fn insert_into_order_book(
+    offer_key: Pubkey,
+    offer: &mut Offer,
+    order_book_page: &mut OrderBookPage,
+) -> Result<()> {
+    if order_book_page.fiat_currency != offer.fiat_currency {
+        return Err(error!(ErrorCode::InvalidOrderBookPage));
+    }
+    if order_book_page.offers.len() >= ORDER_BOOK_PAGE_CAPACITY {
+        return Err(error!(ErrorCode::OrderBookPageFull));
+    }
+
+    order_book_page.offers.push(offer_key);
+    offer.order_book_page = Some(order_book_page.page);
+
+    Ok(())
+}
+
+// Helper function to remove an offer from its order book page (no-op if not listed)
+pub(crate) fn remove_from_order_book(
+    offer_key: Pubkey,
+    offer: &mut Offer,
+    order_book_page: &mut OrderBookPage,
+) -> Result<()> {
+    let page = match offer.order_book_page {
+        Some(page) => page,
+        None => return Ok(()),
+    };
+
+    if order_book_page.fiat_currency != offer.fiat_currency || order_book_page.page != page {
+        return Err(error!(ErrorCode::InvalidOrderBookPage));
+    }
+
+    if let Some(index) = order_book_page.offers.iter().position(|key| *key == offer_key) {
+        order_book_page.offers.swap_remove(index);
+    }
+    offer.order_book_page = None;
+
+    Ok(())
+}
+
 // Helper function to mint trade rewards after trade completion
 fn try_mint_trade_rewards_for_completed_trade(
     seller: &Pubkey,
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{Admin, Reputation, ReputationUpdated};
+use anchor_lang::system_program;
+use crate::state::{Admin, Reputation, ReputationUpdated, TradeHistory, MAX_RECENT_COUNTERPARTIES};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -11,6 +12,16 @@ pub struct CreateReputation<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateReputation<'info> {
+    /// CHECK: Reputation in the layout before volume weighting; resized and validated by hand
+    #[account(mut, seeds = [b"reputation", user.key().as_ref()], bump)]
+    pub reputation: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateReputation<'info> {
     #[account(mut, seeds = [b"reputation", user.key().as_ref()], bump)]
@@ -27,6 +38,21 @@ pub struct UpdateReputation<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct CreateTradeHistory<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + TradeHistory::LEN,
+        seeds = [TradeHistory::SEED.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub trade_history: Account<'info, TradeHistory>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 pub fn create_reputation(ctx: Context<CreateReputation>) -> Result<()> {
     let reputation = &mut ctx.accounts.reputation;
     let user = &ctx.accounts.user;
@@ -38,8 +64,64 @@ pub fn create_reputation(ctx: Context<CreateReputation>) -> Result<()> {
     reputation.disputed_trades = 0;
     reputation.disputes_won = 0;
     reputation.disputes_lost = 0;
-    reputation.rating = 100; // Start with perfect rating
+    reputation.rating = Reputation::NEUTRAL_RATING; // Unproven until trades build confidence
     reputation.last_updated = clock.unix_timestamp;
+    reputation.last_offer_created = 0;
+    reputation.last_dispute_opened = 0;
+    reputation.total_volume = 0;
+    reputation.weighted_successes = 0;
+    reputation.weighted_disputes = 0;
+    reputation.weighted_disputes_won = 0;
+
+    Ok(())
+}
+
+pub fn migrate_reputation(ctx: Context<MigrateReputation>) -> Result<()> {
+    let reputation_info = ctx.accounts.reputation.to_account_info();
+    let new_len = 8 + Reputation::LEN;
+
+    // Only reputations created before the weighted counters were added need migrating
+    if reputation_info.owner != &crate::ID {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+    if reputation_info.data_len() >= new_len {
+        return Err(error!(ErrorCode::ReputationAlreadyMigrated));
+    }
+    if !reputation_info.try_borrow_data()?.starts_with(Reputation::DISCRIMINATOR) {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch));
+    }
+
+    // The user pays the rent for the larger account
+    let minimum_balance = Rent::get()?.minimum_balance(new_len);
+    let shortfall = minimum_balance.saturating_sub(reputation_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: reputation_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    // New fields are appended and zero-filled, so the old record reads as the new layout
+    reputation_info.resize(new_len)?;
+    let mut reputation = Reputation::try_deserialize(&mut &reputation_info.try_borrow_data()?[..])?;
+
+    // Past trades had no recorded volume, so each counts with one unit of weight
+    reputation.weighted_successes = (reputation.successful_trades as u64)
+        .checked_mul(Reputation::WEIGHT_PRECISION)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reputation.weighted_disputes = (reputation.disputed_trades as u64)
+        .checked_mul(Reputation::WEIGHT_PRECISION)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reputation.weighted_disputes_won = (reputation.disputes_won as u64)
+        .checked_mul(Reputation::WEIGHT_PRECISION)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reputation.try_serialize(&mut &mut reputation_info.try_borrow_mut_data()?[..])?;
 
     Ok(())
 }
@@ -49,72 +131,208 @@ pub fn update_reputation(
     successful_trade: bool,
     dispute_resolved: bool,
     dispute_won: bool,
+    trade_amount: u64,
 ) -> Result<()> {
     let reputation = &mut ctx.accounts.reputation;
     let clock = Clock::get()?;
 
+    decay_weights(reputation, clock.unix_timestamp)?;
+
     if successful_trade {
-        reputation.successful_trades += 1;
+        apply_trade_outcome(reputation, TradeOutcome::Completed, trade_amount)?;
     }
 
     if dispute_resolved {
-        reputation.disputed_trades += 1;
-        if dispute_won {
-            reputation.disputes_won += 1;
-        } else {
-            reputation.disputes_lost += 1;
-        }
+        let outcome = if dispute_won { TradeOutcome::DisputeWon } else { TradeOutcome::DisputeLost };
+        apply_trade_outcome(reputation, outcome, trade_amount)?;
     }
 
-    // Recalculate rating based on performance with overflow protection
-    let total_trades = reputation.successful_trades
-        .checked_add(reputation.disputed_trades)
+    recalculate_rating(reputation, clock.unix_timestamp)
+}
+
+pub fn create_trade_history(ctx: Context<CreateTradeHistory>) -> Result<()> {
+    let trade_history = &mut ctx.accounts.trade_history;
+
+    trade_history.user = ctx.accounts.user.key();
+    trade_history.recent_counterparties = [Pubkey::default(); MAX_RECENT_COUNTERPARTIES];
+    trade_history.next_index = 0;
+    trade_history.bump = ctx.bumps.trade_history;
+
+    Ok(())
+}
+
+// Helper function to remember a counterparty, overwriting the oldest entry, if the user created a trade history
+pub(crate) fn record_counterparty(trade_history_info: &AccountInfo, counterparty: Pubkey) -> Result<()> {
+    if trade_history_info.data_is_empty() {
+        return Ok(());
+    }
+    if trade_history_info.owner != &crate::ID {
+        return Err(error!(ErrorCode::Unauthorized));
+    }
+
+    let mut trade_history = TradeHistory::try_deserialize(&mut &trade_history_info.try_borrow_data()?[..])?;
+    let index = trade_history.next_index as usize % MAX_RECENT_COUNTERPARTIES;
+    trade_history.recent_counterparties[index] = counterparty;
+    trade_history.next_index = ((index + 1) % MAX_RECENT_COUNTERPARTIES) as u8;
+    trade_history.try_serialize(&mut &mut trade_history_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+// How a trade ended for one of its parties
+pub(crate) enum TradeOutcome {
+    Completed,
+    DisputeWon,
+    DisputeLost,
+    DisputeSplit, // Verdict divided the escrow evenly
+}
+
+// Helper function to record a trade outcome on a user's reputation
+pub(crate) fn record_trade_outcome(
+    reputation: &mut Reputation,
+    outcome: TradeOutcome,
+    trade_amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    decay_weights(reputation, now)?;
+    apply_trade_outcome(reputation, outcome, trade_amount)?;
+    recalculate_rating(reputation, now)
+}
+
+// Helper function to count a trade outcome, weighted by trade size
+fn apply_trade_outcome(reputation: &mut Reputation, outcome: TradeOutcome, trade_amount: u64) -> Result<()> {
+    // Proportional to the amount traded, so dust trades add next to no weight
+    let weight = (trade_amount as u128)
+        .checked_mul(Reputation::WEIGHT_PRECISION as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(Reputation::VOLUME_WEIGHT_UNIT as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .min((Reputation::MAX_TRADE_WEIGHT * Reputation::WEIGHT_PRECISION) as u128) as u64;
+
+    reputation.total_volume = reputation.total_volume.saturating_add(trade_amount);
+
+    if let TradeOutcome::Completed = outcome {
+        reputation.successful_trades = reputation.successful_trades
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        reputation.weighted_successes = reputation.weighted_successes.saturating_add(weight);
+        return Ok(());
+    }
+
+    reputation.disputed_trades = reputation.disputed_trades
+        .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
-        
-    if total_trades > 0 {
-        // Use checked arithmetic to prevent overflow
-        let success_rate = reputation.successful_trades
+    reputation.weighted_disputes = reputation.weighted_disputes.saturating_add(weight);
+    match outcome {
+        TradeOutcome::DisputeWon => {
+            reputation.disputes_won = reputation.disputes_won
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+            reputation.weighted_disputes_won = reputation.weighted_disputes_won.saturating_add(weight);
+        }
+        TradeOutcome::DisputeLost => {
+            reputation.disputes_lost = reputation.disputes_lost
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        TradeOutcome::DisputeSplit => {
+            reputation.weighted_disputes_won = reputation.weighted_disputes_won.saturating_add(weight / 2);
+        }
+        TradeOutcome::Completed => {}
+    }
+
+    Ok(())
+}
+
+// Helper function to decay the weighted counters by the time since the last update:
+// halved per full half-life, linearly approximated in between
+fn decay_weights(reputation: &mut Reputation, now: i64) -> Result<()> {
+    let elapsed = now.saturating_sub(reputation.last_updated);
+    if elapsed <= 0 {
+        return Ok(());
+    }
+
+    let half_lives = elapsed / Reputation::DECAY_HALF_LIFE;
+    let remainder = (elapsed % Reputation::DECAY_HALF_LIFE) as u128;
+    let decay = |value: u64| -> Result<u64> {
+        if half_lives >= 64 {
+            return Ok(0);
+        }
+        let halved = (value >> half_lives) as u128;
+        let decayed = halved
+            .checked_mul(remainder)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(2 * Reputation::DECAY_HALF_LIFE as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok((halved - decayed) as u64)
+    };
+
+    reputation.weighted_successes = decay(reputation.weighted_successes)?;
+    reputation.weighted_disputes = decay(reputation.weighted_disputes)?;
+    reputation.weighted_disputes_won = decay(reputation.weighted_disputes_won)?;
+
+    Ok(())
+}
+
+// Helper function to recalculate a user's rating from their recent, volume-weighted record
+fn recalculate_rating(reputation: &mut Reputation, now: i64) -> Result<()> {
+    let total_weight = reputation.weighted_successes as u128 + reputation.weighted_disputes as u128;
+
+    reputation.rating = if total_weight == 0 {
+        Reputation::NEUTRAL_RATING
+    } else {
+        let success_rate = (reputation.weighted_successes as u128)
             .checked_mul(100)
             .ok_or(ErrorCode::MathOverflow)?
-            .checked_div(total_trades)
+            .checked_div(total_weight)
             .ok_or(ErrorCode::MathOverflow)?;
-            
-        let dispute_win_rate = if reputation.disputed_trades > 0 {
-            reputation.disputes_won
+
+        let dispute_win_rate = if reputation.weighted_disputes > 0 {
+            (reputation.weighted_disputes_won as u128)
                 .checked_mul(100)
                 .ok_or(ErrorCode::MathOverflow)?
-                .checked_div(reputation.disputed_trades)
+                .checked_div(reputation.weighted_disputes as u128)
                 .ok_or(ErrorCode::MathOverflow)?
         } else {
             100
         };
-        
-        // Rating is weighted average of success rate and dispute win rate
-        // Cap the calculation to prevent u8 overflow (rating should be 0-100)
-        let weighted_success = success_rate
+
+        // Conduct is a weighted average of success rate and dispute win rate
+        let conduct = success_rate
             .checked_mul(70)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(dispute_win_rate.checked_mul(30).ok_or(ErrorCode::MathOverflow)?)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(100)
             .ok_or(ErrorCode::MathOverflow)?;
-        let weighted_dispute = dispute_win_rate
-            .checked_mul(30)
-            .ok_or(ErrorCode::MathOverflow)?;
-        let combined_rating = weighted_success
-            .checked_add(weighted_dispute)
+
+        // Little recent weight pulls the rating towards neutral
+        let confidence = total_weight
+            .checked_mul(100)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div((Reputation::CONFIDENCE_WEIGHT * Reputation::WEIGHT_PRECISION) as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .min(100);
+        let rating = conduct
+            .checked_mul(confidence)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(Reputation::NEUTRAL_RATING as u128 * (100 - confidence))
             .ok_or(ErrorCode::MathOverflow)?
             .checked_div(100)
             .ok_or(ErrorCode::MathOverflow)?;
-            
-        // Ensure rating stays within u8 bounds (0-255, but practically should be 0-100)
-        reputation.rating = if combined_rating > 100 { 100 } else { combined_rating as u8 };
-    }
 
-    reputation.last_updated = clock.unix_timestamp;
+        rating.min(100) as u8
+    };
+
+    reputation.last_updated = now;
 
     // Emit event
     emit!(ReputationUpdated {
         user: reputation.user,
         successful_trades: reputation.successful_trades,
+        total_volume: reputation.total_volume,
         rating: reputation.rating,
     });
 
     Ok(())
-}
\ No newline at end of file
+}
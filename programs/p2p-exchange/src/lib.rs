@@ -8,7 +8,7 @@
 //!
 //! - **Offers**: Trade offers with escrow-backed security
 //! - **Disputes**: Multi-juror dispute resolution system  
-//! - **Reputation**: User rating system based on trade history
+//! - **Reputation**: Volume-weighted, time-decayed user rating based on trade history
 //! - **Admin**: Centralized administration for critical operations
 //!
 //! ## Security Features
@@ -34,6 +34,7 @@ pub mod errors;
 pub mod utils;
 
 use instructions::*;
+use state::{DisputeTimings, PanelTier};
 
 declare_id!("ASU1Gjmx9XMwErZumic9DNTADYzKphtEd1Zy4BFwSpnk");
 
@@ -57,7 +58,65 @@ pub mod p2p_exchange {
         instructions::admin::update_admin_authorities(ctx, secondary_authorities, required_signatures)
     }
 
+    /// Initialize dispute configuration with juror panel size tiers and phase lengths (admin-only)
+    ///
+    /// # Arguments
+    /// * `panel_tiers` - Odd panel sizes keyed by minimum trade amount, ascending from 0
+    /// * `timings` - Evidence, voting, reveal, appeal, fee response, juror inactivity and override timelock lengths in seconds
+    pub fn initialize_dispute_config(
+        ctx: Context<InitializeDisputeConfig>,
+        panel_tiers: Vec<PanelTier>,
+        timings: DisputeTimings,
+    ) -> Result<()> {
+        instructions::admin::initialize_dispute_config(ctx, panel_tiers, timings)
+    }
+
+    /// Replace the juror panel size tiers (admin-only)
+    ///
+    /// # Arguments
+    /// * `panel_tiers` - Odd panel sizes keyed by minimum trade amount, ascending from 0
+    pub fn update_panel_tiers(
+        ctx: Context<UpdateDisputeConfig>,
+        panel_tiers: Vec<PanelTier>,
+    ) -> Result<()> {
+        instructions::admin::update_panel_tiers(ctx, panel_tiers)
+    }
+
+    /// Replace the dispute phase lengths; open disputes keep their own (admin-only)
+    ///
+    /// # Arguments
+    /// * `timings` - Evidence, voting, reveal, appeal, fee response, juror inactivity and override timelock lengths in seconds
+    pub fn update_dispute_timings(
+        ctx: Context<UpdateDisputeConfig>,
+        timings: DisputeTimings,
+    ) -> Result<()> {
+        instructions::admin::update_dispute_timings(ctx, timings)
+    }
+
+    /// Initialize the offer creation and dispute opening cooldowns with the
+    /// `Reputation` defaults (admin-only)
+    pub fn initialize_cooldown_config(ctx: Context<InitializeCooldownConfig>) -> Result<()> {
+        instructions::admin::initialize_cooldown_config(ctx)
+    }
+
+    /// Replace the base cooldowns; reputation tiers scale them per user (admin-only)
+    ///
+    /// # Arguments
+    /// * `offer_creation_cooldown` - Seconds between two offers of a seller, 0 to disable
+    /// * `dispute_opening_cooldown` - Seconds between two disputes opened by a user, 0 to disable
+    pub fn update_cooldowns(
+        ctx: Context<UpdateCooldownConfig>,
+        offer_creation_cooldown: i64,
+        dispute_opening_cooldown: i64,
+    ) -> Result<()> {
+        instructions::admin::update_cooldowns(ctx, offer_creation_cooldown, dispute_opening_cooldown)
+    }
+
     /// Create a new P2P exchange offer with escrowed SOL
+    ///
+    /// Rate limited per seller by the `CooldownConfig` offer creation cooldown, scaled by reputation tier.
+    /// The seller must have created a reputation account (`create_reputation`) first; every
+    /// trading party needs one, and trade and dispute outcomes are recorded on it.
     /// 
     /// # Arguments
     /// * `amount` - SOL amount to sell (in lamports)
@@ -76,17 +135,101 @@ pub mod p2p_exchange {
         instructions::offers::create_offer(ctx, amount, fiat_amount, fiat_currency, payment_method, created_at)
     }
 
-    /// Make an offer visible to the public
-    pub fn list_offer(ctx: Context<ListOffer>) -> Result<()> {
-        instructions::offers::list_offer(ctx)
+    /// Create an order book page for a currency
+    ///
+    /// # Arguments
+    /// * `fiat_currency` - Currency code the page indexes (e.g., "USD")
+    /// * `page` - Page index; pages must be created in order starting at 0
+    pub fn create_order_book_page(
+        ctx: Context<CreateOrderBookPage>,
+        fiat_currency: String,
+        page: u32,
+    ) -> Result<()> {
+        instructions::offers::create_order_book_page(ctx, fiat_currency, page)
+    }
+
+    /// Make an offer (new or unlisted) visible to the public
+    ///
+    /// # Arguments
+    /// * `page` - Order book page to insert the offer into
+    pub fn list_offer(ctx: Context<ListOffer>, page: u32) -> Result<()> {
+        instructions::offers::list_offer(ctx, page)
+    }
+
+    /// Update the terms of an offer that has not been accepted yet
+    ///
+    /// # Arguments
+    /// * `amount` - New SOL amount to sell (escrow is topped up or refunded to match)
+    /// * `fiat_amount` - New fiat currency amount requested
+    /// * `payment_method` - New payment method description (max 50 chars)
+    pub fn update_offer(
+        ctx: Context<UpdateOffer>,
+        amount: u64,
+        fiat_amount: u64,
+        payment_method: String,
+    ) -> Result<()> {
+        instructions::offers::update_offer(ctx, amount, fiat_amount, payment_method)
+    }
+
+    /// Temporarily hide a listed offer; escrow stays locked until relisted or cancelled
+    pub fn unlist_offer(ctx: Context<UnlistOffer>) -> Result<()> {
+        instructions::offers::unlist_offer(ctx)
+    }
+
+    /// Grow an offer created in an older layout to the current one
+    ///
+    /// Offers listed before the order book index go back to `Created`, since no page
+    /// indexes them, and must be listed again; new fields start zeroed (`terms_version` 0).
+    /// The seller pays the extra rent.
+    pub fn migrate_offer(ctx: Context<MigrateOffer>) -> Result<()> {
+        instructions::offers::migrate_offer(ctx)
+    }
+
+    /// Cancel an unaccepted offer and refund the escrowed SOL
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        instructions::offers::cancel_offer(ctx)
+    }
+
+    /// List many offers in one call
+    ///
+    /// Remaining accounts: `[offer, order_book_page]` pairs
+    pub fn batch_list_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+        instructions::batch::batch_list_offers(ctx)
+    }
+
+    /// Delist many offers in one call
+    ///
+    /// Remaining accounts: `[offer, order_book_page]` pairs
+    pub fn batch_delist_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+        instructions::batch::batch_delist_offers(ctx)
+    }
+
+    /// Cancel many offers in one call and refund their escrows
+    ///
+    /// Remaining accounts: `[offer, escrow_account, order_book_page]` triples
+    /// (pass the program ID as the page of an unlisted offer)
+    pub fn batch_cancel_offers<'info>(ctx: Context<'_, '_, 'info, 'info, BatchOffers<'info>>) -> Result<()> {
+        instructions::batch::batch_cancel_offers(ctx)
     }
 
     /// Accept an offer and lock in security bond
     ///
+    /// The offer is removed from its order book page, since nobody else can accept it.
+    /// The buyer must have created a reputation account (`create_reputation`) first.
+    ///
     /// # Arguments  
     /// * `security_bond` - Additional bond amount (in lamports)
-    pub fn accept_offer(ctx: Context<AcceptOffer>, security_bond: u64) -> Result<()> {
-        instructions::offers::accept_offer(ctx, security_bond)
+    /// * `expected_amount` - SOL amount the buyer saw; rejected if it changed
+    /// * `expected_fiat_amount` - Fiat amount the buyer saw; rejected if it changed
+    /// * `expected_terms_version` - Offer terms version the buyer saw; rejected if it changed
+    pub fn accept_offer(
+        ctx: Context<AcceptOffer>,
+        security_bond: u64,
+        expected_amount: u64,
+        expected_fiat_amount: u64,
+        expected_terms_version: u32,
+    ) -> Result<()> {
+        instructions::offers::accept_offer(ctx, security_bond, expected_amount, expected_fiat_amount, expected_terms_version)
     }
 
     /// Mark fiat payment as sent by buyer
@@ -99,12 +242,14 @@ pub mod p2p_exchange {
         instructions::offers::confirm_fiat_receipt(ctx)
     }
 
-    /// Release escrowed SOL to buyer (completes the trade)
+    /// Release escrowed SOL to buyer (completes the trade and records it on both reputations)
     pub fn release_sol(ctx: Context<ReleaseSol>) -> Result<()> {
         instructions::offers::release_sol(ctx)
     }
 
-    /// Open a dispute for a trade
+    /// Open a dispute for a trade, posting the dispute fee into the dispute escrow
+    ///
+    /// Rate limited per initiator by the `CooldownConfig` dispute opening cooldown, scaled by reputation tier.
     ///
     /// # Arguments
     /// * `reason` - Detailed reason for the dispute (max 200 chars)
@@ -112,51 +257,255 @@ pub mod p2p_exchange {
         instructions::disputes::open_dispute(ctx, reason)
     }
 
-    /// Assign 3 jurors to a dispute (admin-only)
-    pub fn assign_jurors(ctx: Context<AssignJurors>) -> Result<()> {
+    /// Match the initiator's dispute fee as the respondent (before the response deadline)
+    pub fn pay_dispute_fee(ctx: Context<PayDisputeFee>) -> Result<()> {
+        instructions::disputes::pay_dispute_fee(ctx)
+    }
+
+    /// Award the dispute to the initiator when the respondent never matched the fee (permissionless)
+    pub fn claim_default_judgment(ctx: Context<ClaimDefaultJudgment>) -> Result<()> {
+        instructions::disputes::claim_default_judgment(ctx)
+    }
+
+    /// Initialize the juror registry (admin-only)
+    ///
+    /// # Arguments
+    /// * `min_stake` - Minimum stake (in lamports) required to serve as a juror
+    pub fn initialize_juror_registry(ctx: Context<InitializeJurorRegistry>, min_stake: u64) -> Result<()> {
+        instructions::jurors::initialize_juror_registry(ctx, min_stake)
+    }
+
+    /// Register as a juror by staking SOL
+    ///
+    /// # Arguments
+    /// * `stake_amount` - SOL to stake (in lamports), at least the registry minimum
+    pub fn register_juror(ctx: Context<RegisterJuror>, stake_amount: u64) -> Result<()> {
+        instructions::jurors::register_juror(ctx, stake_amount)
+    }
+
+    /// Add SOL to an existing juror stake
+    ///
+    /// # Arguments
+    /// * `amount` - Additional SOL to stake (in lamports)
+    pub fn increase_juror_stake(ctx: Context<IncreaseJurorStake>, amount: u64) -> Result<()> {
+        instructions::jurors::increase_juror_stake(ctx, amount)
+    }
+
+    /// Leave the juror registry and withdraw the remaining stake
+    ///
+    /// Suspended jurors may leave too; only an unsettled dispute keeps the stake locked.
+    pub fn unregister_juror(ctx: Context<UnregisterJuror>) -> Result<()> {
+        instructions::jurors::unregister_juror(ctx)
+    }
+
+    /// Suspend or reinstate a juror (admin-only)
+    pub fn set_juror_suspension(ctx: Context<SetJurorSuspension>, suspended: bool) -> Result<()> {
+        instructions::jurors::set_juror_suspension(ctx, suspended)
+    }
+
+    /// Withdraw the slashed stake and unpaid juror fees held by the juror registry (admin-only)
+    pub fn sweep_juror_registry(ctx: Context<SweepJurorRegistry>) -> Result<()> {
+        instructions::jurors::sweep_juror_registry(ctx)
+    }
+
+    /// Settle a juror's stake after a dispute, slashing missed or consistently minority votes
+    pub fn settle_juror(ctx: Context<SettleJuror>) -> Result<()> {
+        instructions::jurors::settle_juror(ctx)
+    }
+
+    /// Draw a juror panel from the staked registry (permissionless)
+    ///
+    /// Entropy comes from the hash of `Dispute.draw_slot` in the SlotHashes sysvar
+    /// combined with the dispute key. Remaining accounts: the `JurorStake` of every
+    /// non-excluded candidate examined, in draw order.
+    pub fn draw_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJurors<'info>>) -> Result<()> {
+        instructions::jurors::draw_jurors(ctx)
+    }
+
+    /// Step down from a dispute panel before committing a vote
+    ///
+    /// The seat is refilled by `draw_replacement_jurors` from the hash of a future slot.
+    pub fn recuse_juror(ctx: Context<RecuseJuror>) -> Result<()> {
+        instructions::jurors::recuse_juror(ctx)
+    }
+
+    /// Vacate the seat of a juror who has not committed a vote within the inactivity
+    /// window since being seated (permissionless)
+    ///
+    /// The inactive juror is slashed for a missed vote; the seat is refilled by
+    /// `draw_replacement_jurors`.
+    pub fn replace_inactive_juror(ctx: Context<ReplaceInactiveJuror>) -> Result<()> {
+        instructions::jurors::replace_inactive_juror(ctx)
+    }
+
+    /// Refill vacated panel seats from the registry (permissionless)
+    ///
+    /// Entropy comes from the hash of `Dispute.replacement_draw_slot`, a slot scheduled after
+    /// the latest vacancy. Replacements get an inactivity window to commit,
+    /// extending the commit phase by at most one window. Remaining accounts: the
+    /// `JurorStake` of every non-excluded candidate examined, in draw order.
+    pub fn draw_replacement_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJurors<'info>>) -> Result<()> {
+        instructions::jurors::draw_replacement_jurors(ctx)
+    }
+
+    /// Assign a panel of registered jurors to a dispute (requires the admin multisig threshold)
+    ///
+    /// Remaining accounts: the `JurorStake` of each juror, one per panel seat, followed by
+    /// the signing secondary admin authorities
+    pub fn assign_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, AssignJurors<'info>>) -> Result<()> {
         instructions::disputes::assign_jurors(ctx)
     }
 
-    /// Submit evidence for a dispute
+    /// Submit an evidence item for a dispute (rate-limited per party)
+    ///
+    /// # Arguments
+    /// * `content_hash` - SHA-256 of the off-chain file, so jurors can detect swapped files
+    /// * `uri` - Location of the evidence file (max 300 chars)
+    /// * `mime_type` - MIME type of the file (max 64 chars)
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        content_hash: [u8; 32],
+        uri: String,
+        mime_type: String,
+    ) -> Result<()> {
+        instructions::disputes::submit_evidence(ctx, content_hash, uri, mime_type)
+    }
+
+    /// Request the single phase extension for a dispute (parties only, during the evidence phase)
+    pub fn request_phase_extension(ctx: Context<RequestPhaseExtension>) -> Result<()> {
+        instructions::disputes::request_phase_extension(ctx)
+    }
+
+    /// Approve a pending phase extension; a panel majority extends evidence and voting phases
+    pub fn approve_phase_extension(ctx: Context<ApprovePhaseExtension>) -> Result<()> {
+        instructions::disputes::approve_phase_extension(ctx)
+    }
+
+    /// Commit a hidden vote as an assigned juror
     ///
     /// # Arguments
-    /// * `evidence_url` - URL to evidence document (max 300 chars, max 5 per party)
-    pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_url: String) -> Result<()> {
-        instructions::disputes::submit_evidence(ctx, evidence_url)
+    /// * `commitment` - `sha256(dispute || juror || buyer_percentage || salt)`
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::disputes::commit_vote(ctx, commitment)
     }
 
-    /// Cast a vote as an assigned juror
+    /// Reveal a previously committed vote; votes are tallied on reveal
+    ///
+    /// The verdict is reached once every juror revealed, or once the reveal phase ends
+    /// with a quorum of reveals.
     ///
     /// # Arguments
-    /// * `vote_for_buyer` - true if voting for buyer, false for seller
-    pub fn cast_vote(ctx: Context<CastVote>, vote_for_buyer: bool) -> Result<()> {
-        instructions::disputes::cast_vote(ctx, vote_for_buyer)
+    /// * `buyer_percentage` - Share of the escrow (0-100) the juror awards to the buyer
+    /// * `salt` - Salt used when computing the commitment
+    pub fn reveal_vote(ctx: Context<RevealVote>, buyer_percentage: u8, salt: [u8; 32]) -> Result<()> {
+        instructions::disputes::reveal_vote(ctx, buyer_percentage, salt)
     }
 
-    /// Execute the final verdict and distribute funds (admin-only)
-    /// Note: Tied votes (1-1-1) will be rejected and require re-voting
-    pub fn execute_verdict(ctx: Context<ExecuteVerdict>) -> Result<()> {
+    /// Propose a split of the escrow to settle a dispute before a verdict
+    ///
+    /// # Arguments
+    /// * `buyer_amount` - Lamports of the escrow (amount plus security bond) for the buyer;
+    ///   the seller receives the rest
+    pub fn propose_settlement(ctx: Context<ProposeSettlement>, buyer_amount: u64) -> Result<()> {
+        instructions::disputes::propose_settlement(ctx, buyer_amount)
+    }
+
+    /// Accept the counterparty's settlement proposal, paying out the escrow and resolving the dispute
+    ///
+    /// # Arguments
+    /// * `expected_buyer_amount` - Buyer amount the accepter agreed to
+    pub fn accept_settlement(ctx: Context<AcceptSettlement>, expected_buyer_amount: u64) -> Result<()> {
+        instructions::disputes::accept_settlement(ctx, expected_buyer_amount)
+    }
+
+    /// Appeal a verdict to a larger, freshly drawn panel by posting an appeal bond
+    /// (a party that did not receive the full escrow, within the appeal window)
+    pub fn appeal_verdict(ctx: Context<AppealVerdict>) -> Result<()> {
+        instructions::disputes::appeal_verdict(ctx)
+    }
+
+    /// Execute the final verdict and distribute funds (permissionless)
+    ///
+    /// Splits the escrow by the median juror allocation, records the outcome on both
+    /// parties' reputations, settles fees and appeal bond, and pays the majority jurors;
+    /// fees no majority juror is owed go to the juror registry.
+    /// Remaining accounts: `[juror, vote, user_rewards]` for every seat of the current panel,
+    /// where `vote` and `user_rewards` are the juror's PDAs (initialized or not)
+    pub fn execute_verdict<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteVerdict<'info>>) -> Result<()> {
         instructions::disputes::execute_verdict(ctx)
     }
 
-    /// Initialize a reputation account for a user
+    /// Propose an admin override of a dispute (admin multi-sig)
+    ///
+    /// Writes an `OverrideRecord` with the awarded buyer percentage and a hash of the
+    /// off-chain justification. Remaining accounts: signing secondary admin authorities.
+    pub fn propose_force_resolve(
+        ctx: Context<ProposeForceResolve>,
+        buyer_percentage: u8,
+        justification_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::disputes::propose_force_resolve(ctx, buyer_percentage, justification_hash)
+    }
+
+    /// Withdraw a proposed override before it is executed (admin multi-sig)
+    ///
+    /// Closes the `OverrideRecord`, so a new override can be proposed.
+    /// Remaining accounts: signing secondary admin authorities.
+    pub fn cancel_force_resolve(ctx: Context<CancelForceResolve>) -> Result<()> {
+        instructions::disputes::cancel_force_resolve(ctx)
+    }
+
+    /// Execute a proposed override once its timelock has elapsed (admin multi-sig)
+    ///
+    /// Fees and any appeal bond are refunded and the `OverrideRecord` is sealed.
+    pub fn force_resolve(ctx: Context<ForceResolve>) -> Result<()> {
+        instructions::disputes::force_resolve(ctx)
+    }
+
+    /// Resolve a dispute that expired without a verdict (permissionless)
+    ///
+    /// Reveals short of the quorum never decide the escrow: follows the appealed verdict
+    /// and refunds the appeal bond, else refunds the seller's amount and the buyer's security bond
+    pub fn resolve_expired_dispute(ctx: Context<ResolveExpiredDispute>) -> Result<()> {
+        instructions::disputes::resolve_expired_dispute(ctx)
+    }
+
+    /// Initialize a record of a user's recent trade counterparties
+    pub fn create_trade_history(ctx: Context<CreateTradeHistory>) -> Result<()> {
+        instructions::reputation::create_trade_history(ctx)
+    }
+
+    /// Initialize a reputation account for a user (required before creating or accepting offers)
     pub fn create_reputation(ctx: Context<CreateReputation>) -> Result<()> {
         instructions::reputation::create_reputation(ctx)
     }
 
-    /// Update user reputation based on trade outcomes (admin-only)
+    /// Grow a reputation created before volume weighting to the current layout
+    ///
+    /// Past trades are carried over into the weighted counters with one unit of weight each;
+    /// the user pays the extra rent.
+    pub fn migrate_reputation(ctx: Context<MigrateReputation>) -> Result<()> {
+        instructions::reputation::migrate_reputation(ctx)
+    }
+
+    /// Correct a user's reputation by hand (admin-only)
+    ///
+    /// Reputation is updated automatically when a trade completes or a dispute is resolved;
+    /// this instruction only exists to fix up records.
     ///
     /// # Arguments
     /// * `successful_trade` - Whether the trade completed successfully
     /// * `dispute_resolved` - Whether a dispute was resolved
     /// * `dispute_won` - Whether the user won the dispute (if applicable)
+    /// * `trade_amount` - Trade size (in lamports) used to weight the outcome
     pub fn update_reputation(
         ctx: Context<UpdateReputation>,
         successful_trade: bool,
         dispute_resolved: bool,
         dispute_won: bool,
+        trade_amount: u64,
     ) -> Result<()> {
-        instructions::reputation::update_reputation(ctx, successful_trade, dispute_resolved, dispute_won)
+        instructions::reputation::update_reputation(ctx, successful_trade, dispute_resolved, dispute_won, trade_amount)
     }
 
     /// Initialize the reward token system (admin-only)
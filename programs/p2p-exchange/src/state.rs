@@ -45,6 +45,8 @@ pub struct Offer {
     pub created_at: i64,
     pub updated_at: i64,
     pub dispute_id: Option<Pubkey>,
+    pub order_book_page: Option<u32>, // Order book page index while listed
+    pub terms_version: u32, // Incremented whenever the terms change
 }
 
 impl Offer {
@@ -58,7 +60,26 @@ impl Offer {
                            4 + MAX_PAYMENT_METHOD_LEN + // payment_method (with length prefix)
                            8 +  // created_at
                            8 +  // updated_at
-                           33;  // dispute_id (Option<Pubkey>)
+                           33 + // dispute_id (Option<Pubkey>)
+                           5 +  // order_book_page (Option<u32>)
+                           4;   // terms_version
+}
+
+#[account]
+pub struct OrderBookPage {
+    pub fiat_currency: String,
+    pub page: u32,
+    pub offers: Vec<Pubkey>, // Listed offers, unordered
+    pub bump: u8,
+}
+
+impl OrderBookPage {
+    pub const LEN: usize = 4 + MAX_FIAT_CURRENCY_LEN + // fiat_currency (with length prefix)
+                           4 +  // page
+                           4 + 32 * ORDER_BOOK_PAGE_CAPACITY + // offers (with length prefix)
+                           1;   // bump
+
+    pub const SEED: &'static str = "order_book";
 }
 
 #[account]
@@ -66,54 +87,487 @@ pub struct Dispute {
     pub offer: Pubkey,
     pub initiator: Pubkey,
     pub respondent: Pubkey,
+    pub initiator_role: u8, // DisputeRole of the party that opened the dispute
     pub reason: String,
     pub status: u8,
-    pub jurors: [Pubkey; 3],
-    pub evidence_buyer: [String; MAX_EVIDENCE_ITEMS],
-    pub evidence_buyer_count: u8,
-    pub evidence_seller: [String; MAX_EVIDENCE_ITEMS],
-    pub evidence_seller_count: u8,
-    pub votes_for_buyer: u8,
-    pub votes_for_seller: u8,
+    pub panel_size: u8, // Odd number of jurors, chosen from the trade amount
+    pub jurors: Vec<Pubkey>, // Jurors of every round; the current panel starts at panel_start
+    pub panel_start: u8,
+    pub seated_at: Vec<i64>, // When each seat of the current panel was filled
+    pub vacant_seats: u16, // Bitmask of current panel seats awaiting a replacement draw
+    pub replacement_draw_slot: u64, // Slot whose hash seeds the draw for vacant seats
+    pub vacated_jurors: Vec<Pubkey>, // Jurors who left a seat; never drawn for this dispute again
+    pub evidence_count: u32, // Evidence PDAs are indexed by submission order
+    pub buyer_last_evidence_at: i64,
+    pub seller_last_evidence_at: i64,
+    pub votes_committed: u8,
+    pub buyer_allocations: Vec<u8>, // Revealed buyer payout percentages of the current round
     pub created_at: i64,
     pub resolved_at: i64,
+    pub jurors_settled: u32, // Bitmask of juror seats whose stake outcome was settled
+    pub draw_slot: u64, // Slot whose hash seeds the juror draw
+    pub draw_seed: [u8; 32], // Recorded entropy of the juror draw (zero if assigned by admin)
+    pub round_started_at: i64, // Start of the current (original or appeal) round
+    pub verdict_reached_at: i64,
+    pub appeal_round: u8,
+    pub appellant: Option<Pubkey>,
+    pub appealed_buyer_percentage: u8, // Median allocation of the round that was appealed
+    pub round_verdicts: Vec<RoundVerdict>, // Verdict of each appealed round, judged against its own panel
+    pub evidence_ends_at: i64, // Phase clock, set when jurors are assigned
+    pub voting_ends_at: i64, // End of the commit phase; reveals close a reveal period later
+    pub extension_requested_by: Option<Pubkey>, // Party that used the single phase extension
+    pub extension_approvals: u16, // Bitmask of current panel seats approving the extension
+    pub extension_granted: bool,
+    pub dispute_fee: u64, // Fee each party posts into the dispute escrow
+    pub response_deadline: i64, // Respondent must match the fee by then or default
+    pub respondent_fee_paid: bool,
+    pub juror_fee_pool: u64, // Lost fees and forfeited appeal bond owed to the majority jurors
+    pub settlement_proposer: Option<Pubkey>, // Party with a pending settlement proposal
+    pub settlement_buyer_amount: u64, // Lamports of the escrow proposed for the buyer
+    pub settled_by_parties: bool,
+    pub overridden: bool, // Resolved by an admin override instead of the panel
+    pub timings: DisputeTimings, // Phase lengths, copied from the dispute config when opened
 }
 
 impl Dispute {
     pub const LEN: usize = 32 + // offer
                            32 + // initiator
                            32 + // respondent
+                           1 +  // initiator_role
                            4 + MAX_DISPUTE_REASON_LEN + // reason (with length prefix)
                            1 +  // status
-                           96 + // jurors (3 * 32)
-                           (4 + MAX_EVIDENCE_URL_LEN) * MAX_EVIDENCE_ITEMS + // evidence_buyer (fixed array with length prefixes)
-                           1 +  // evidence_buyer_count
-                           (4 + MAX_EVIDENCE_URL_LEN) * MAX_EVIDENCE_ITEMS + // evidence_seller (fixed array with length prefixes)
-                           1 +  // evidence_seller_count
-                           1 +  // votes_for_buyer
-                           1 +  // votes_for_seller
+                           1 +  // panel_size
+                           4 + 32 * MAX_PANEL_SIZE as usize * (1 + MAX_APPEAL_ROUNDS as usize) + // jurors (with length prefix)
+                           1 +  // panel_start
+                           4 + 8 * MAX_PANEL_SIZE as usize + // seated_at (with length prefix)
+                           2 +  // vacant_seats
+                           8 +  // replacement_draw_slot
+                           4 + 32 * MAX_VACATED_JURORS + // vacated_jurors (with length prefix)
+                           4 +  // evidence_count
+                           8 +  // buyer_last_evidence_at
+                           8 +  // seller_last_evidence_at
+                           1 +  // votes_committed
+                           4 + MAX_PANEL_SIZE as usize + // buyer_allocations (with length prefix)
                            8 +  // created_at
-                           8;   // resolved_at
+                           8 +  // resolved_at
+                           4 +  // jurors_settled
+                           8 +  // draw_slot
+                           32 + // draw_seed
+                           8 +  // round_started_at
+                           8 +  // verdict_reached_at
+                           1 +  // appeal_round
+                           33 + // appellant (Option<Pubkey>)
+                           1 +  // appealed_buyer_percentage
+                           4 + RoundVerdict::LEN * MAX_APPEAL_ROUNDS as usize + // round_verdicts (with length prefix)
+                           8 +  // evidence_ends_at
+                           8 +  // voting_ends_at
+                           33 + // extension_requested_by (Option<Pubkey>)
+                           2 +  // extension_approvals
+                           1 +  // extension_granted
+                           8 +  // dispute_fee
+                           8 +  // response_deadline
+                           1 +  // respondent_fee_paid
+                           8 +  // juror_fee_pool
+                           33 + // settlement_proposer (Option<Pubkey>)
+                           8 +  // settlement_buyer_amount
+                           1 +  // settled_by_parties
+                           1 +  // overridden
+                           DisputeTimings::LEN; // timings
+
+    // Dispute phase constants (in seconds)
+    pub const EXTENSION_DURATION: i64 = 172800; // 48 hours added to evidence and voting phases
+    pub const EVIDENCE_COOLDOWN: i64 = 60; // Minimum seconds between evidence items from the same party
+
+    // Juror draw constants
+    pub const DRAW_DELAY_SLOTS: u64 = 10; // Draw uses the hash of a slot after the dispute opened
+    pub const MAX_DRAW_ATTEMPTS: u8 = 128; // Candidates examined before giving up
+
+    // Dispute fee constants
+    pub const DISPUTE_FEE_BASIS_POINTS: u64 = 100; // 1% of the trade amount
+    pub const MIN_DISPUTE_FEE: u64 = 5_000_000; // 0.005 SOL
+
+    // Appeal constants
+    pub const APPEAL_BOND_BASIS_POINTS: u64 = 1000; // 10% of the trade amount
+    pub const MIN_APPEAL_BOND: u64 = 10_000_000; // 0.01 SOL
+
+    // Verdict constants
+    pub const MAX_ALLOCATION_DEVIATION: u8 = 25; // Percentage points from the median before a vote counts as minority
+
+    /// Jurors of the current panel needed for a quorum (of reveals or extension approvals)
+    pub fn quorum(&self) -> u8 {
+        self.panel_size / 2 + 1
+    }
+
+    /// Whether the revealed votes form a verdict: every juror revealed, or the reveal
+    /// phase ended after a quorum of reveals
+    pub fn has_verdict(&self, now: i64) -> bool {
+        self.status == DisputeStatus::VerdictReached as u8
+            || (self.status == DisputeStatus::Voting as u8
+                && self.buyer_allocations.len() >= self.quorum() as usize
+                && now > self.reveal_ends_at())
+    }
+
+    /// Median buyer payout percentage of the revealed votes
+    pub fn median_buyer_percentage(&self) -> Option<u8> {
+        if self.buyer_allocations.is_empty() {
+            return None;
+        }
+
+        let mut allocations = self.buyer_allocations.clone();
+        allocations.sort_unstable();
+        let mid = allocations.len() / 2;
+        if allocations.len().is_multiple_of(2) {
+            Some(((allocations[mid - 1] as u16 + allocations[mid] as u16) / 2) as u8)
+        } else {
+            Some(allocations[mid])
+        }
+    }
+
+    /// Jurors of the current round
+    pub fn current_panel(&self) -> &[Pubkey] {
+        &self.jurors[self.panel_start as usize..]
+    }
+
+    /// Verdict a juror seat is judged against: the median of the seat's own round,
+    /// or none if the current round missed its quorum of reveals
+    pub fn round_buyer_percentage(&self, seat: usize) -> Option<u8> {
+        match self.round_verdicts.iter().find(|round| seat < round.panel_end as usize) {
+            Some(round) => Some(round.buyer_percentage),
+            None if self.buyer_allocations.len() >= self.quorum() as usize => self.median_buyer_percentage(),
+            None => None,
+        }
+    }
+
+    /// Whether a seat's allocation strays too far from the median verdict of its round
+    pub fn is_minority_allocation(&self, seat: usize, buyer_percentage: u8) -> bool {
+        match self.round_buyer_percentage(seat) {
+            Some(median) => median.abs_diff(buyer_percentage) > Self::MAX_ALLOCATION_DEVIATION,
+            None => false,
+        }
+    }
+
+    /// Seat of a juror on the current panel, unless the juror vacated it
+    pub fn seat_of(&self, juror: &Pubkey) -> Option<usize> {
+        self.current_panel()
+            .iter()
+            .position(|key| key == juror)
+            .filter(|seat| self.vacant_seats & (1 << seat) == 0)
+    }
+
+    /// Starts the evidence and voting phases once a panel is seated
+    pub fn start_phase_clock(&mut self, now: i64) {
+        self.evidence_ends_at = now.saturating_add(self.timings.evidence_period);
+        self.voting_ends_at = self.evidence_ends_at.saturating_add(self.timings.voting_period);
+        self.seated_at = vec![now; self.panel_size as usize];
+        self.vacant_seats = 0;
+        self.replacement_draw_slot = 0;
+    }
+
+    /// Time a seat's juror has been able to commit since: seating, but no earlier than
+    /// the start of the commit phase
+    pub fn commit_eligible_since(&self, seat: usize) -> i64 {
+        self.seated_at[seat].max(self.evidence_ends_at)
+    }
+
+    /// Gives a newly seated juror an inactivity window to commit, but never pushes the
+    /// commit deadline past one window beyond the regular commit phase
+    pub fn extend_voting_for_replacement(&mut self, now: i64) {
+        let latest_voting_ends_at = self.evidence_ends_at
+            .saturating_add(self.timings.voting_period)
+            .saturating_add(self.timings.inactivity_window);
+        let replacement_deadline = now
+            .saturating_add(self.timings.inactivity_window)
+            .min(latest_voting_ends_at);
+        self.voting_ends_at = self.voting_ends_at.max(replacement_deadline);
+    }
+
+    /// Time the median became final: the last juror's reveal, else the end of the reveal phase
+    pub fn verdict_final_at(&self) -> i64 {
+        if self.buyer_allocations.len() >= self.panel_size as usize {
+            self.verdict_reached_at
+        } else {
+            self.reveal_ends_at()
+        }
+    }
+
+    /// End of the reveal phase; before a panel is seated the full dispute deadline applies
+    pub fn reveal_ends_at(&self) -> i64 {
+        if self.voting_ends_at == 0 {
+            self.round_started_at.saturating_add(self.timings.round_duration())
+        } else {
+            self.voting_ends_at.saturating_add(self.timings.reveal_period)
+        }
+    }
+}
+
+#[account]
+pub struct Evidence {
+    pub dispute: Pubkey,
+    pub submitter: Pubkey,
+    pub role: u8, // DisputeRole of the submitter
+    pub index: u32,
+    pub content_hash: [u8; 32], // SHA-256 of the off-chain file
+    pub uri: String,
+    pub mime_type: String,
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl Evidence {
+    pub const LEN: usize = 32 + // dispute
+                           32 + // submitter
+                           1 +  // role
+                           4 +  // index
+                           32 + // content_hash
+                           4 + MAX_EVIDENCE_URL_LEN + // uri (with length prefix)
+                           4 + MAX_EVIDENCE_MIME_TYPE_LEN + // mime_type (with length prefix)
+                           8 +  // submitted_at
+                           1;   // bump
+
+    pub const SEED: &'static str = "evidence";
+}
+
+#[account]
+pub struct DisputeEscrow {
+    pub dispute: Pubkey,
+    pub initiator_fee: u64,
+    pub respondent_fee: u64,
+    pub appellant: Pubkey,
+    pub appeal_bond: u64,
+    pub bump: u8,
+}
+
+impl DisputeEscrow {
+    pub const LEN: usize = 32 + // dispute
+                           8 +  // initiator_fee
+                           8 +  // respondent_fee
+                           32 + // appellant
+                           8 +  // appeal_bond
+                           1;   // bump
+
+    pub const SEED: &'static str = "dispute_escrow";
+}
+
+#[account]
+pub struct OverrideRecord {
+    pub dispute: Pubkey,
+    pub buyer_percentage: u8, // Share of the escrow awarded to the buyer
+    pub justification_hash: [u8; 32], // Hash of the off-chain justification document
+    pub proposed_by: Vec<Pubkey>, // Admin signers of the proposal
+    pub proposed_at: i64,
+    pub executed_by: Vec<Pubkey>, // Admin signers of the execution
+    pub executed_at: i64, // 0 until executed; the record is immutable afterwards
+    pub bump: u8,
+}
+
+impl OverrideRecord {
+    pub const MAX_SIGNERS: usize = 3; // Primary plus secondary admin authorities
+
+    pub const LEN: usize = 32 + // dispute
+                           1 +  // buyer_percentage
+                           32 + // justification_hash
+                           4 + 32 * Self::MAX_SIGNERS + // proposed_by (with length prefix)
+                           8 +  // proposed_at
+                           4 + 32 * Self::MAX_SIGNERS + // executed_by (with length prefix)
+                           8 +  // executed_at
+                           1;   // bump
+
+    pub const SEED: &'static str = "override_record";
+}
+
+#[account]
+pub struct DisputeConfig {
+    pub authority: Pubkey,
+    pub panel_tiers: Vec<PanelTier>, // Sorted by min_amount, first tier starts at 0
+    pub timings: DisputeTimings, // Phase lengths for newly opened disputes
+    pub bump: u8,
+}
+
+impl DisputeConfig {
+    pub const LEN: usize = 32 + // authority
+                           4 + PanelTier::LEN * MAX_PANEL_TIERS + // panel_tiers (with length prefix)
+                           DisputeTimings::LEN + // timings
+                           1;   // bump
+
+    pub const SEED: &'static str = "dispute_config";
+
+    /// Panel size for a trade of the given amount
+    pub fn panel_size_for(&self, amount: u64) -> u8 {
+        self.panel_tiers
+            .iter()
+            .rev()
+            .find(|tier| amount >= tier.min_amount)
+            .map(|tier| tier.panel_size)
+            .unwrap_or(MIN_PANEL_SIZE)
+    }
+}
+
+#[account]
+pub struct CooldownConfig {
+    pub authority: Pubkey,
+    pub offer_creation_cooldown: i64, // Base seconds between two offers of a seller
+    pub dispute_opening_cooldown: i64, // Base seconds between two disputes opened by a user
+    pub bump: u8,
+}
+
+impl CooldownConfig {
+    pub const LEN: usize = 32 + // authority
+                           8 +  // offer_creation_cooldown
+                           8 +  // dispute_opening_cooldown
+                           1;   // bump
+
+    pub const SEED: &'static str = "cooldown_config";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct DisputeTimings {
+    pub evidence_period: i64, // Seconds for evidence submission (e.g. 48 hours)
+    pub voting_period: i64, // Seconds for the vote commit phase (e.g. 7 days)
+    pub reveal_period: i64, // Seconds for the vote reveal phase (e.g. 48 hours)
+    pub appeal_window: i64, // Seconds after a verdict to appeal (e.g. 48 hours)
+    pub response_period: i64, // Seconds for the respondent to match the dispute fee (e.g. 48 hours)
+    pub inactivity_window: i64, // Seconds of commit phase before a silent juror can be replaced (e.g. 72 hours)
+    pub override_timelock: i64, // Seconds between an admin override proposal and its execution (e.g. 24 hours)
+}
+
+impl DisputeTimings {
+    pub const LEN: usize = 8 + // evidence_period
+                           8 + // voting_period
+                           8 + // reveal_period
+                           8 + // appeal_window
+                           8 + // response_period
+                           8 + // inactivity_window
+                           8;  // override_timelock
+
+    /// Length of one round, from its start to the end of the reveal phase
+    pub fn round_duration(&self) -> i64 {
+        self.evidence_period
+            .saturating_add(self.voting_period)
+            .saturating_add(self.reveal_period)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RoundVerdict {
+    pub panel_end: u8, // Seat after the last juror of the round
+    pub buyer_percentage: u8, // Median allocation the round's panel reached
+}
 
-    // Dispute deadline constants (in seconds)
-    pub const EVIDENCE_SUBMISSION_DEADLINE: i64 = 172800; // 48 hours for evidence submission
-    pub const VOTING_DEADLINE: i64 = 604800; // 7 days for voting phase
-    pub const TOTAL_DISPUTE_DEADLINE: i64 = 776800; // 9 days total (48h + 7d)
+impl RoundVerdict {
+    pub const LEN: usize = 1 + // panel_end
+                           1;  // buyer_percentage
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PanelTier {
+    pub min_amount: u64, // Trade amount (in lamports) at which this tier starts
+    pub panel_size: u8,
+}
+
+impl PanelTier {
+    pub const LEN: usize = 8 + // min_amount
+                           1;  // panel_size
 }
 
 #[account]
 pub struct Vote {
     pub dispute: Pubkey,
     pub juror: Pubkey,
-    pub vote_for_buyer: bool,
-    pub timestamp: i64,
+    pub commitment: [u8; 32], // sha256(dispute || juror || buyer_percentage || salt)
+    pub revealed: bool,
+    pub buyer_percentage: u8, // Share of the escrow awarded to the buyer; only meaningful once revealed
+    pub timestamp: i64, // Commit time
+    pub revealed_at: i64,
 }
 
 impl Vote {
     pub const LEN: usize = 32 + // dispute
                            32 + // juror
-                           1 +  // vote_for_buyer
-                           8;   // timestamp
+                           32 + // commitment
+                           1 +  // revealed
+                           1 +  // buyer_percentage
+                           8 +  // timestamp
+                           8;   // revealed_at
+}
+
+#[account]
+pub struct JurorRegistry {
+    pub authority: Pubkey,
+    pub min_stake: u64,          // Minimum lamports staked to be eligible
+    pub total_staked: u64,
+    pub forfeited_lamports: u64, // Slashed stake and unpaid juror fees held by the registry
+    pub jurors: Vec<Pubkey>,     // Registered jurors
+    pub bump: u8,
+}
+
+impl JurorRegistry {
+    pub const LEN: usize = 32 + // authority
+                           8 +  // min_stake
+                           8 +  // total_staked
+                           8 +  // forfeited_lamports
+                           4 + 32 * MAX_REGISTERED_JURORS + // jurors (with length prefix)
+                           1;   // bump
+
+    pub const SEED: &'static str = "juror_registry";
+}
+
+#[account]
+pub struct JurorStake {
+    pub juror: Pubkey,
+    pub stake: u64,
+    pub status: u8,
+    pub active_disputes: u16,    // Assigned disputes not yet settled
+    pub disputes_served: u32,
+    pub missed_votes: u32,
+    pub minority_votes: u32,
+    pub consecutive_minority: u8,
+    pub slash_count: u8,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl JurorStake {
+    pub const LEN: usize = 32 + // juror
+                           8 +  // stake
+                           1 +  // status
+                           2 +  // active_disputes
+                           4 +  // disputes_served
+                           4 +  // missed_votes
+                           4 +  // minority_votes
+                           1 +  // consecutive_minority
+                           1 +  // slash_count
+                           8 +  // registered_at
+                           1;   // bump
+
+    pub const SEED: &'static str = "juror_stake";
+
+    // Slashing parameters
+    pub const SLASH_BASIS_POINTS: u64 = 1000; // 10% of stake per slash
+    pub const MINORITY_STRIKE_LIMIT: u8 = 3; // Consecutive minority votes before slashing
+    pub const MAX_SLASHES: u8 = 3; // Suspended after this many slashes
+
+    // Slash reasons reported in `JurorSlashed`
+    pub const SLASH_REASON_MISSED_VOTE: &'static str = "missed_vote"; // No vote committed
+    pub const SLASH_REASON_UNREVEALED_VOTE: &'static str = "unrevealed_vote"; // Committed but never revealed
+    pub const SLASH_REASON_MINORITY: &'static str = "minority"; // Too many consecutive minority votes
+    pub const SLASH_REASON_INACTIVE: &'static str = "inactive"; // Replaced for not committing in time
+}
+
+#[account]
+pub struct TradeHistory {
+    pub user: Pubkey,
+    pub recent_counterparties: [Pubkey; MAX_RECENT_COUNTERPARTIES], // Ring buffer
+    pub next_index: u8,
+    pub bump: u8,
+}
+
+impl TradeHistory {
+    pub const LEN: usize = 32 + // user
+                           32 * MAX_RECENT_COUNTERPARTIES + // recent_counterparties
+                           1 +  // next_index
+                           1;   // bump
+
+    pub const SEED: &'static str = "trade_history";
 }
 
 #[account]
@@ -126,7 +580,11 @@ pub struct Reputation {
     pub rating: u8,
     pub last_updated: i64,
     pub last_offer_created: i64, // Rate limiting for offer creation
-    pub last_dispute_opened: i64, // Rate limiting for dispute opening  
+    pub last_dispute_opened: i64, // Rate limiting for dispute opening
+    pub total_volume: u64, // Lamports traded across completed and disputed trades
+    pub weighted_successes: u64, // Volume-weighted, time-decayed successful trades
+    pub weighted_disputes: u64, // Volume-weighted, time-decayed disputed trades
+    pub weighted_disputes_won: u64, // Volume-weighted, time-decayed dispute wins (splits count half)
 }
 
 impl Reputation {
@@ -138,11 +596,42 @@ impl Reputation {
                            1 +  // rating
                            8 +  // last_updated
                            8 +  // last_offer_created
-                           8;   // last_dispute_opened
+                           8 +  // last_dispute_opened
+                           8 +  // total_volume
+                           8 +  // weighted_successes
+                           8 +  // weighted_disputes
+                           8;   // weighted_disputes_won
 
-    // Rate limiting constants (in seconds)
+    // Rate limiting defaults (in seconds) the `CooldownConfig` starts with
     pub const OFFER_CREATION_COOLDOWN: i64 = 300; // 5 minutes
     pub const DISPUTE_OPENING_COOLDOWN: i64 = 3600; // 1 hour
+
+    // Rating constants
+    pub const NEUTRAL_RATING: u8 = 50; // Rating of a user without meaningful history
+    pub const WEIGHT_PRECISION: u64 = 1_000; // Fixed-point scale of the weighted counters
+    pub const VOLUME_WEIGHT_UNIT: u64 = 1_000_000_000; // Each SOL traded adds one unit of weight
+    pub const MAX_TRADE_WEIGHT: u64 = 100; // Weight cap of a single trade, in units
+    pub const DECAY_HALF_LIFE: i64 = 7_776_000; // 90 days for past conduct to lose half its weight
+    pub const CONFIDENCE_WEIGHT: u64 = 20; // Units of recent weight needed for full confidence
+
+    // Cooldown tier thresholds
+    pub const LOW_RATING: u8 = 40; // Below this rating cooldowns are quadrupled (under NEUTRAL_RATING, so new users are not)
+    pub const ESTABLISHED_TRADES: u32 = 5; // Below this many successful trades cooldowns are doubled
+    pub const TRUSTED_TRADES: u32 = 50; // Trusted users get half the cooldown
+    pub const TRUSTED_RATING: u8 = 90;
+
+    // Scale a base cooldown by the user's reputation tier
+    pub fn scaled_cooldown(&self, cooldown: i64) -> i64 {
+        if self.rating < Self::LOW_RATING {
+            cooldown.saturating_mul(4)
+        } else if self.successful_trades < Self::ESTABLISHED_TRADES {
+            cooldown.saturating_mul(2)
+        } else if self.successful_trades >= Self::TRUSTED_TRADES && self.rating >= Self::TRUSTED_RATING {
+            cooldown / 2
+        } else {
+            cooldown
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -155,6 +644,7 @@ pub enum OfferStatus {
     DisputeOpened,
     Completed,
     Cancelled,
+    Unlisted, // Paused by the seller, escrow retained
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -167,12 +657,53 @@ pub enum DisputeStatus {
     Resolved,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum JurorStatus {
+    Active,
+    Suspended,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOfferAction {
+    List,
+    Delist,
+    Cancel,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeRole {
+    Buyer,
+    Seller,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiredDisputePolicy {
+    AppealedVerdict, // Appeal round missed its quorum; the original verdict stands
+    Refund,          // Round missed its quorum; each party gets its own deposit back
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BatchOfferResult {
+    pub offer: Pubkey,
+    pub success: bool,
+    pub error_code: u32, // 0 on success; builtin program errors report their index
+}
+
 // Input validation constants
 pub const MAX_FIAT_CURRENCY_LEN: usize = 10;  // e.g., "USD", "EUR"
 pub const MAX_PAYMENT_METHOD_LEN: usize = 50; // e.g., "Bank Transfer"
 pub const MAX_DISPUTE_REASON_LEN: usize = 200;
 pub const MAX_EVIDENCE_URL_LEN: usize = 300;
-pub const MAX_EVIDENCE_ITEMS: usize = 5; // Maximum evidence items per party
+pub const MAX_EVIDENCE_MIME_TYPE_LEN: usize = 64; // e.g., "application/pdf"
+pub const ORDER_BOOK_PAGE_CAPACITY: usize = 32; // Maximum offers per order book page
+pub const MAX_BATCH_OFFERS: usize = 10; // Maximum offers per batch instruction
+pub const MAX_REGISTERED_JURORS: usize = 64; // Maximum jurors in the registry
+pub const MAX_RECENT_COUNTERPARTIES: usize = 8; // Counterparties remembered per user
+pub const MIN_PANEL_SIZE: u8 = 3; // Smallest juror panel
+pub const MAX_PANEL_SIZE: u8 = 9; // Largest juror panel
+pub const MAX_PANEL_TIERS: usize = 4; // Panel size tiers in the dispute config
+pub const MAX_APPEAL_ROUNDS: u8 = 1; // Appeals allowed per dispute
+pub const MAX_VACATED_JURORS: usize = 16; // Recusals and inactivity replacements per dispute
 
 // Events
 #[event]
@@ -184,6 +715,43 @@ pub struct OfferCreated {
     pub fiat_currency: String,
 }
 
+#[event]
+pub struct OfferListed {
+    pub offer: Pubkey,
+    pub fiat_currency: String,
+    pub page: u32,
+}
+
+#[event]
+pub struct OfferUpdated {
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub amount: u64,
+    pub fiat_amount: u64,
+    pub payment_method: String,
+    pub terms_version: u32,
+}
+
+#[event]
+pub struct OfferUnlisted {
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct OfferCancelled {
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub refunded_amount: u64,
+}
+
+#[event]
+pub struct OffersBatchProcessed {
+    pub seller: Pubkey,
+    pub action: u8,
+    pub results: Vec<BatchOfferResult>,
+}
+
 #[event]
 pub struct OfferAccepted {
     pub offer: Pubkey,
@@ -215,33 +783,230 @@ pub struct DisputeOpened {
     pub dispute: Pubkey,
     pub offer: Pubkey,
     pub initiator: Pubkey,
+    pub initiator_role: u8,
+    pub dispute_fee: u64,
+    pub response_deadline: i64,
     pub reason: String,
 }
 
+#[event]
+pub struct DisputeFeePaid {
+    pub dispute: Pubkey,
+    pub respondent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DefaultJudgment {
+    pub dispute: Pubkey,
+    pub winner: Pubkey,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct SettlementProposed {
+    pub dispute: Pubkey,
+    pub proposer: Pubkey,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct SettlementAccepted {
+    pub dispute: Pubkey,
+    pub accepter: Pubkey,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct ForceResolveProposed {
+    pub dispute: Pubkey,
+    pub override_record: Pubkey,
+    pub buyer_percentage: u8,
+    pub justification_hash: [u8; 32],
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ForceResolveCancelled {
+    pub dispute: Pubkey,
+    pub override_record: Pubkey,
+    pub cancelled_by: Vec<Pubkey>, // Admin signers of the cancellation
+}
+
+#[event]
+pub struct DisputeForceResolved {
+    pub dispute: Pubkey,
+    pub override_record: Pubkey,
+    pub buyer_percentage: u8,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct DisputeFeesSettled {
+    pub dispute: Pubkey,
+    pub buyer_refund: u64,
+    pub seller_refund: u64,
+    pub juror_fee_pool: u64,
+}
+
+#[event]
+pub struct JurorCompensated {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub amount: u64,
+    pub reward_amount: u64,
+}
+
+#[event]
+pub struct JurorFeesForfeited {
+    pub dispute: Pubkey,
+    pub amount: u64, // Juror fee pool left over after paying the majority jurors
+}
+
 #[event]
 pub struct JurorsAssigned {
     pub dispute: Pubkey,
-    pub jurors: [Pubkey; 3],
+    pub jurors: Vec<Pubkey>,
+}
+
+#[event]
+pub struct JurorsDrawn {
+    pub dispute: Pubkey,
+    pub jurors: Vec<Pubkey>,
+    pub draw_slot: u64,
+    pub draw_seed: [u8; 32],
+}
+
+#[event]
+pub struct JurorSeatVacated {
+    pub dispute: Pubkey,
+    pub seat: u8,
+    pub previous_juror: Pubkey,
+    pub recused: bool, // Stepped down, rather than replaced for inactivity
+    pub replacement_draw_slot: u64,
+}
+
+#[event]
+pub struct JurorReplaced {
+    pub dispute: Pubkey,
+    pub seat: u8,
+    pub new_juror: Pubkey,
+    pub draw_seed: [u8; 32],
+}
+
+#[event]
+pub struct JurorDrawRescheduled {
+    pub dispute: Pubkey,
+    pub draw_slot: u64,
 }
 
 #[event]
 pub struct EvidenceSubmitted {
     pub dispute: Pubkey,
+    pub evidence: Pubkey,
     pub submitter: Pubkey,
-    pub evidence_url: String,
+    pub role: u8,
+    pub index: u32,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub mime_type: String,
 }
 
 #[event]
-pub struct VoteCast {
+pub struct PhaseExtensionRequested {
+    pub dispute: Pubkey,
+    pub requested_by: Pubkey,
+}
+
+#[event]
+pub struct PhaseExtensionApproved {
     pub dispute: Pubkey,
     pub juror: Pubkey,
-    pub vote_for_buyer: bool,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct PhasesExtended {
+    pub dispute: Pubkey,
+    pub evidence_ends_at: i64,
+    pub voting_ends_at: i64,
+}
+
+#[event]
+pub struct VoteCommitted {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+}
+
+#[event]
+pub struct VoteRevealed {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub buyer_percentage: u8,
+}
+
+#[event]
+pub struct DisputeAppealed {
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub appeal_bond: u64,
+    pub appeal_round: u8,
+    pub panel_size: u8,
+}
+
+#[event]
+pub struct AppealBondSettled {
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct ExpiredDisputeResolved {
+    pub dispute: Pubkey,
+    pub policy: u8,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
 }
 
 #[event]
 pub struct VerdictExecuted {
     pub dispute: Pubkey,
-    pub winner: Pubkey,
+    pub buyer_percentage: u8,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct JurorRegistered {
+    pub juror: Pubkey,
+    pub stake: u64,
+}
+
+#[event]
+pub struct JurorUnregistered {
+    pub juror: Pubkey,
+    pub refunded_stake: u64,
+}
+
+#[event]
+pub struct JurorSlashed {
+    pub juror: Pubkey,
+    pub dispute: Pubkey,
+    pub amount: u64,
+    pub reason: String, // One of the JurorStake::SLASH_REASON_* values
+    pub suspended: bool,
+}
+
+#[event]
+pub struct JurorRegistrySwept {
+    pub recipient: Pubkey,
     pub amount: u64,
 }
 
@@ -249,6 +1014,7 @@ pub struct VerdictExecuted {
 pub struct ReputationUpdated {
     pub user: Pubkey,
     pub successful_trades: u32,
+    pub total_volume: u64,
     pub rating: u8,
 }
 
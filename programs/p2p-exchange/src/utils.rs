@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
+use crate::state::EscrowAccount;
 
 /// Common utility functions for input validation and processing
 /// 
@@ -52,4 +53,75 @@ pub fn validate_and_process_string(input: &str, max_length: usize) -> Result<Str
     let trimmed = validate_and_trim_string(input)?;
     validate_string_length(&trimmed, max_length)?;
     Ok(trimmed)
-}
\ No newline at end of file
+}
+
+/// Validates an ISO 4217 style currency code (exactly three uppercase letters)
+/// 
+/// # Arguments
+/// * `fiat_currency` - The currency code to validate
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if valid, error if the format is wrong
+pub fn validate_currency_code(fiat_currency: &str) -> Result<()> {
+    if fiat_currency.len() != 3 {
+        return Err(error!(ErrorCode::InvalidCurrencyCode));
+    }
+    if !fiat_currency.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(error!(ErrorCode::InvalidCurrencyCode));
+    }
+    Ok(())
+}
+
+/// Transfers lamports out of an offer escrow PDA
+/// 
+/// # Arguments
+/// * `escrow_account` - The escrow PDA holding the funds
+/// * `recipient` - The account receiving the lamports
+/// * `amount` - Lamports to transfer
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if the transfer succeeded
+pub fn transfer_from_escrow<'info>(
+    escrow_account: &Account<'info, EscrowAccount>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    transfer_from_pda(&escrow_account.to_account_info(), recipient, amount)
+}
+
+/// Transfers lamports out of an account owned by this program
+/// 
+/// The System program can only debit system-owned accounts without data, so lamports
+/// are moved directly. The account must stay rent exempt afterwards.
+/// 
+/// # Arguments
+/// * `pda` - The program-owned account holding the funds
+/// * `recipient` - The account receiving the lamports
+/// * `amount` - Lamports to transfer
+/// 
+/// # Returns
+/// * `Result<()>` - Ok if the transfer succeeded
+pub fn transfer_from_pda<'info>(
+    pda: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let remaining = pda.lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    if remaining < Rent::get()?.minimum_balance(pda.data_len()) {
+        return Err(error!(ErrorCode::InsufficientFunds));
+    }
+    let credited = recipient.lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    **pda.try_borrow_mut_lamports()? = remaining;
+    **recipient.try_borrow_mut_lamports()? = credited;
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
 import { P2pExchange } from '../target/types/p2p_exchange';
-import { Keypair, SystemProgram, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import { Keypair, SystemProgram, LAMPORTS_PER_SOL, PublicKey, SYSVAR_SLOT_HASHES_PUBKEY } from '@solana/web3.js';
 import { expect } from 'chai';
+import { createHash, randomBytes } from 'crypto';
 
 describe('Comprehensive P2P Exchange Tests', () => {
   const provider = anchor.AnchorProvider.env();
@@ -10,6 +11,108 @@ describe('Comprehensive P2P Exchange Tests', () => {
 
   const program = anchor.workspace.P2pExchange as Program<P2pExchange>;
 
+  const offerEscrowPda = (offer: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow"), offer.toBuffer()],
+    program.programId
+  )[0];
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // Numeric code of a program error, as reported in batch results
+  const errorCode = (name: string) => program.idl.errors.find((error) => error.name === name).code;
+
+  // Events emitted by a confirmed transaction, decoded from its logs
+  const transactionEvents = async (signature: string) => {
+    const transaction = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    return Array.from(parser.parseLogs(transaction.meta.logMessages));
+  };
+
+  const jurorStakePda = (juror: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("juror_stake"), juror.toBuffer()],
+    program.programId
+  )[0];
+
+  const votePda = (dispute: PublicKey, juror: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("vote"), dispute.toBuffer(), juror.toBuffer()],
+    program.programId
+  )[0];
+
+  const userRewardsPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("user_rewards"), user.toBuffer()],
+    program.programId
+  )[0];
+
+  const [rewardTokenPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("reward_token")],
+    program.programId
+  );
+
+  // Juror, vote and rewards accounts of every seat of the current panel, paid out by execute_verdict
+  const verdictJurorAccounts = async (dispute: PublicKey) => {
+    const disputeAccount = await program.account.dispute.fetch(dispute);
+    return disputeAccount.jurors.slice(disputeAccount.panelStart).flatMap((juror) => [
+      { pubkey: juror, isSigner: false, isWritable: true },
+      { pubkey: votePda(dispute, juror), isSigner: false, isWritable: false },
+      { pubkey: userRewardsPda(juror), isSigner: false, isWritable: true },
+    ]);
+  };
+
+  // Mirrors the program's sha256(dispute || juror || buyer_percentage || salt)
+  const voteCommitment = (dispute: PublicKey, juror: PublicKey, buyerPercentage: number, salt: Buffer) =>
+    Array.from(
+      createHash('sha256')
+        .update(Buffer.concat([dispute.toBuffer(), juror.toBuffer(), Buffer.from([buyerPercentage]), salt]))
+        .digest()
+    );
+
+  // Holds both parties' dispute fees
+  const disputeEscrowPda = (dispute: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("dispute_escrow"), dispute.toBuffer()],
+    program.programId
+  )[0];
+
+  // Pays the respondent's matching dispute fee, after which jurors can be seated
+  const payDisputeFee = (dispute: PublicKey, respondent: Keypair) => program.methods
+    .payDisputeFee()
+    .accounts({
+      dispute,
+      disputeEscrow: disputeEscrowPda(dispute),
+      respondent: respondent.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([respondent])
+    .rpc();
+
+  // Evidence items are indexed by submission order
+  const evidencePda = (dispute: PublicKey, index: number) => {
+    const indexSeed = Buffer.alloc(4);
+    indexSeed.writeUInt32LE(index);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("evidence"), dispute.toBuffer(), indexSeed],
+      program.programId
+    )[0];
+  };
+
+  // Uninitialized unless the user created a trade history
+  const tradeHistoryPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("trade_history"), user.toBuffer()],
+    program.programId
+  )[0];
+
+  const reputationPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), user.toBuffer()],
+    program.programId
+  )[0];
+
+  const [cooldownConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cooldown_config")],
+    program.programId
+  );
+
   let admin: Keypair;
   let seller: Keypair;
   let buyer: Keypair;
@@ -19,7 +122,30 @@ describe('Comprehensive P2P Exchange Tests', () => {
   let offerKeypair: Keypair;
   let disputeKeypair: Keypair;
   let adminPda: PublicKey;
+  let jurorRegistryPda: PublicKey;
+  let disputeConfigPda: PublicKey;
   let escrowPda: PublicKey;
+  let orderBookPagePda: PublicKey;
+
+  before(async () => {
+    // Offers in these tests are listed on the first USD order book page
+    const page = Buffer.alloc(4);
+    page.writeUInt32LE(0);
+    [orderBookPagePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("order_book"), Buffer.from("USD"), page],
+      program.programId
+    );
+
+    await program.methods
+      .createOrderBookPage("USD", 0)
+      .accounts({
+        orderBookPage: orderBookPagePda,
+        previousPage: null,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
 
   beforeEach(async () => {
     admin = Keypair.generate();
@@ -37,6 +163,16 @@ describe('Comprehensive P2P Exchange Tests', () => {
       program.programId
     );
 
+    [jurorRegistryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("juror_registry")],
+      program.programId
+    );
+
+    [disputeConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute_config")],
+      program.programId
+    );
+
     [escrowPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("escrow"), offerKeypair.publicKey.toBuffer()],
       program.programId
@@ -53,9 +189,162 @@ describe('Comprehensive P2P Exchange Tests', () => {
       provider.connection.confirmTransaction(
         await provider.connection.requestAirdrop(buyer.publicKey, 3 * LAMPORTS_PER_SOL)
       ),
+      ...[juror1, juror2, juror3].map(async (juror) =>
+        provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(juror.publicKey, 2 * LAMPORTS_PER_SOL)
+        )
+      ),
     ]);
+
+    // Trading requires a reputation account
+    await createReputation(seller);
+    await createReputation(buyer);
   });
 
+  const createReputation = (user: Keypair) => program.methods
+    .createReputation()
+    .accounts({
+      reputation: reputationPda(user.publicKey),
+      user: user.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([user])
+    .rpc();
+
+  // Creates the cooldown config unless an earlier test already did, with the
+  // cooldowns turned off so tests can create several offers in a row
+  const ensureCooldownConfig = async () => {
+    if (await provider.connection.getAccountInfo(cooldownConfigPda)) {
+      return;
+    }
+
+    await program.methods
+      .initializeCooldownConfig()
+      .accounts({
+        cooldownConfig: cooldownConfigPda,
+        admin: adminPda,
+        authority: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateCooldowns(new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        cooldownConfig: cooldownConfigPda,
+        admin: adminPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  };
+
+  // Creates a 1 SOL offer of the seller (or of another owner)
+  const createSellerOffer = async (offer: Keypair, owner: Keypair = seller) => {
+    await program.methods
+      .createOffer(
+        new anchor.BN(LAMPORTS_PER_SOL),
+        new anchor.BN(1000),
+        "USD",
+        "Bank Transfer",
+        new anchor.BN(Math.floor(Date.now() / 1000))
+      )
+      .accounts({
+        offer: offer.publicKey,
+        seller: owner.publicKey,
+        escrowAccount: offerEscrowPda(offer.publicKey),
+        reputation: reputationPda(owner.publicKey),
+        cooldownConfig: cooldownConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner, offer])
+      .rpc();
+  };
+
+  // Short dispute phases (in seconds) so tests can wait them out
+  const disputeTimings = {
+    evidencePeriod: new anchor.BN(3),
+    votingPeriod: new anchor.BN(10),
+    revealPeriod: new anchor.BN(5),
+    appealWindow: new anchor.BN(3),
+    responsePeriod: new anchor.BN(3),
+    inactivityWindow: new anchor.BN(3),
+    overrideTimelock: new anchor.BN(3),
+  };
+
+  // Fee each party posts to dispute a 1 SOL trade (1% of the amount)
+  const disputeFee = 0.01 * LAMPORTS_PER_SOL;
+
+  // Sleeps until a phase of the given length (in seconds) is over
+  const waitOut = (seconds: anchor.BN | number) => sleep((Number(seconds) + 1) * 1000);
+
+  // Length of a dispute round, from juror assignment to the end of the reveal phase
+  const roundDuration = disputeTimings.evidencePeriod
+    .add(disputeTimings.votingPeriod)
+    .add(disputeTimings.revealPeriod);
+
+  // Creates the dispute config (a three-juror panel for every amount) and the
+  // juror registry unless an earlier test already did
+  const ensureDisputeSetup = async () => {
+    if (!(await provider.connection.getAccountInfo(disputeConfigPda))) {
+      await program.methods
+        .initializeDisputeConfig([{ minAmount: new anchor.BN(0), panelSize: 3 }], disputeTimings)
+        .accounts({
+          disputeConfig: disputeConfigPda,
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    if (!(await provider.connection.getAccountInfo(jurorRegistryPda))) {
+      await program.methods
+        .initializeJurorRegistry(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+        .accounts({
+          jurorRegistry: jurorRegistryPda,
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    }
+  };
+
+  // Funds and registers the jurors that are not in the registry yet
+  const registerJurors = async (jurors: Keypair[]) => {
+    for (const juror of jurors) {
+      if (await provider.connection.getAccountInfo(jurorStakePda(juror.publicKey))) {
+        continue;
+      }
+
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(juror.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+      await program.methods
+        .registerJuror(new anchor.BN(LAMPORTS_PER_SOL))
+        .accounts({
+          jurorRegistry: jurorRegistryPda,
+          jurorStake: jurorStakePda(juror.publicKey),
+          juror: juror.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([juror])
+        .rpc();
+    }
+  };
+
+  // Stake accounts of the seated jurors, passed to assign_jurors
+  const jurorStakeAccounts = (jurors: Keypair[] = [juror1, juror2, juror3]) =>
+    jurors.map((juror) => ({ pubkey: jurorStakePda(juror.publicKey), isSigner: false, isWritable: true }));
+
+  // Offers currently listed on the first USD page
+  const listedOffers = async () =>
+    (await program.account.orderBookPage.fetch(orderBookPagePda)).offers.map((offer) => offer.toString());
+
   describe('Admin Setup', () => {
     it('Initializes admin', async () => {
       await program.methods
@@ -85,6 +374,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
         })
         .signers([admin])
         .rpc();
+
+      await ensureCooldownConfig();
     });
 
     it('Creates an offer with PDA escrow', async () => {
@@ -106,6 +397,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
           escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
@@ -139,6 +432,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
           escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
@@ -146,9 +441,10 @@ describe('Comprehensive P2P Exchange Tests', () => {
 
       // List the offer
       await program.methods
-        .listOffer()
+        .listOffer(0)
         .accounts({
           offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
           seller: seller.publicKey,
         })
         .signers([seller])
@@ -157,11 +453,18 @@ describe('Comprehensive P2P Exchange Tests', () => {
       // Accept the offer
       const securityBond = 0.1 * LAMPORTS_PER_SOL;
       await program.methods
-        .acceptOffer(new anchor.BN(securityBond))
+        .acceptOffer(
+          new anchor.BN(securityBond),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
         .accounts({
           offer: offerKeypair.publicKey,
           buyer: buyer.publicKey,
           escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
           systemProgram: SystemProgram.programId,
         })
         .signers([buyer])
@@ -171,6 +474,11 @@ describe('Comprehensive P2P Exchange Tests', () => {
       expect(offerAccount.buyer.toString()).to.equal(buyer.publicKey.toString());
       expect(offerAccount.status).to.equal(2); // Accepted status
       expect(offerAccount.securityBond.toString()).to.equal(securityBond.toString());
+
+      // Accepted offers leave the order book
+      const orderBookPage = await program.account.orderBookPage.fetch(orderBookPagePda);
+      expect(orderBookPage.offers.map((offer) => offer.toString()))
+        .to.not.include(offerKeypair.publicKey.toString());
     });
 
     it('Completes full trade workflow', async () => {
@@ -187,15 +495,18 @@ describe('Comprehensive P2P Exchange Tests', () => {
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
           escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
         .rpc();
 
       await program.methods
-        .listOffer()
+        .listOffer(0)
         .accounts({
           offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
           seller: seller.publicKey,
         })
         .signers([seller])
@@ -203,11 +514,18 @@ describe('Comprehensive P2P Exchange Tests', () => {
 
       // Accept offer
       await program.methods
-        .acceptOffer(new anchor.BN(0))
+        .acceptOffer(
+          new anchor.BN(0),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
         .accounts({
           offer: offerKeypair.publicKey,
           buyer: buyer.publicKey,
           escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
           systemProgram: SystemProgram.programId,
         })
         .signers([buyer])
@@ -243,6 +561,10 @@ describe('Comprehensive P2P Exchange Tests', () => {
           seller: seller.publicKey,
           buyer: buyer.publicKey,
           escrowAccount: escrowPda,
+          sellerTradeHistory: tradeHistoryPda(seller.publicKey),
+          buyerTradeHistory: tradeHistoryPda(buyer.publicKey),
+          buyerReputation: reputationPda(buyer.publicKey),
+          sellerReputation: reputationPda(seller.publicKey),
           systemProgram: SystemProgram.programId,
         })
         .signers([seller])
@@ -254,21 +576,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
       expect(offerAccount.status).to.equal(6); // Completed status
       expect(buyerBalanceAfter).to.be.greaterThan(buyerBalanceBefore);
     });
-  });
-
-  describe('Dispute Resolution Workflow', () => {
-    beforeEach(async () => {
-      // Initialize admin and create accepted offer
-      await program.methods
-        .initializeAdmin()
-        .accounts({
-          admin: adminPda,
-          authority: admin.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([admin])
-        .rpc();
 
+    it('Rejects migrating an offer that already uses the current layout', async () => {
       await program.methods
         .createOffer(
           new anchor.BN(LAMPORTS_PER_SOL),
@@ -281,248 +590,2418 @@ describe('Comprehensive P2P Exchange Tests', () => {
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
           escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
         .rpc();
 
+      try {
+        await program.methods
+          .migrateOffer()
+          .accounts({
+            offer: offerKeypair.publicKey,
+            seller: seller.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OfferAlreadyMigrated");
+      }
+    });
+  });
+
+  describe('Batch Offer Operations', () => {
+    const pageAccounts = (offers: Keypair[]) => offers.flatMap((offer) => [
+      { pubkey: offer.publicKey, isWritable: true, isSigner: false },
+      { pubkey: orderBookPagePda, isWritable: true, isSigner: false },
+    ]);
+
+    // Per-offer results of a batch transaction
+    const batchResults = async (signature: string) =>
+      (await transactionEvents(signature)).find((event) => event.name === 'offersBatchProcessed').data.results;
+
+    it('Reports per-offer results for a mixed batch', async () => {
+      const offers = [Keypair.generate(), Keypair.generate()];
+      for (const offer of offers) {
+        await createSellerOffer(offer);
+      }
+
+      // The second offer is already listed, so only the first one can be listed by the batch
       await program.methods
-        .listOffer()
+        .listOffer(0)
         .accounts({
-          offer: offerKeypair.publicKey,
+          offer: offers[1].publicKey,
+          orderBookPage: orderBookPagePda,
           seller: seller.publicKey,
         })
         .signers([seller])
         .rpc();
 
-      await program.methods
-        .acceptOffer(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+      const signature = await program.methods
+        .batchListOffers()
         .accounts({
-          offer: offerKeypair.publicKey,
-          buyer: buyer.publicKey,
-          escrowAccount: escrowPda,
+          seller: seller.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([buyer])
-        .rpc();
+        .remainingAccounts(pageAccounts(offers))
+        .signers([seller])
+        .rpc({ commitment: 'confirmed' });
+
+      const results = await batchResults(signature);
+      expect(results[0].success).to.be.true;
+      expect(results[0].errorCode).to.equal(0);
+      expect(results[1].success).to.be.false;
+      expect(results[1].errorCode).to.equal(errorCode('invalidOfferStatus'));
+
+      for (const offer of offers) {
+        const offerAccount = await program.account.offer.fetch(offer.publicKey);
+        expect(offerAccount.status).to.equal(1); // Listed status
+      }
+      expect(await listedOffers()).to.include.members(offers.map((offer) => offer.publicKey.toString()));
     });
 
-    it('Opens a dispute', async () => {
-      const reason = "Buyer did not send fiat payment";
+    it("Rejects another seller's offer without touching it", async () => {
+      const otherSeller = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(otherSeller.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+      await createReputation(otherSeller);
+      const offer = Keypair.generate();
+      await createSellerOffer(offer, otherSeller);
 
-      await program.methods
-        .openDispute(reason)
+      const signature = await program.methods
+        .batchListOffers()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          offer: offerKeypair.publicKey,
-          initiator: seller.publicKey,
-          respondent: buyer.publicKey,
+          seller: seller.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([seller, disputeKeypair])
-        .rpc();
+        .remainingAccounts(pageAccounts([offer]))
+        .signers([seller])
+        .rpc({ commitment: 'confirmed' });
 
-      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
-      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      const [result] = await batchResults(signature);
+      expect(result.success).to.be.false;
+      expect(result.errorCode).to.equal(errorCode('unauthorized'));
 
-      expect(disputeAccount.initiator.toString()).to.equal(seller.publicKey.toString());
-      expect(disputeAccount.respondent.toString()).to.equal(buyer.publicKey.toString());
-      expect(disputeAccount.reason).to.equal(reason);
-      expect(disputeAccount.status).to.equal(0); // Opened status
-      expect(offerAccount.status).to.equal(5); // DisputeOpened status
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(0); // Still Created
+      expect(await listedOffers()).to.not.include(offer.publicKey.toString());
     });
 
-    it('Assigns jurors to dispute', async () => {
-      // Open dispute first
+    it('Delists offers and keeps their escrow', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
       await program.methods
-        .openDispute("Payment issue")
+        .batchListOffers()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          offer: offerKeypair.publicKey,
-          initiator: seller.publicKey,
-          respondent: buyer.publicKey,
+          seller: seller.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([seller, disputeKeypair])
+        .remainingAccounts(pageAccounts([offer]))
+        .signers([seller])
         .rpc();
+      const escrowBalance = await provider.connection.getBalance(offerEscrowPda(offer.publicKey));
 
-      // Assign jurors
       await program.methods
-        .assignJurors()
+        .batchDelistOffers()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror1: juror1.publicKey,
-          juror2: juror2.publicKey,
-          juror3: juror3.publicKey,
-          admin: adminPda,
-          authority: admin.publicKey,
+          seller: seller.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .remainingAccounts(pageAccounts([offer]))
+        .signers([seller])
         .rpc();
 
-      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
-      expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
-      expect(disputeAccount.jurors[0].toString()).to.equal(juror1.publicKey.toString());
-      expect(disputeAccount.jurors[1].toString()).to.equal(juror2.publicKey.toString());
-      expect(disputeAccount.jurors[2].toString()).to.equal(juror3.publicKey.toString());
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(8); // Unlisted status
+      expect(offerAccount.orderBookPage).to.be.null;
+      expect(await listedOffers()).to.not.include(offer.publicKey.toString());
+      expect(await provider.connection.getBalance(offerEscrowPda(offer.publicKey))).to.equal(escrowBalance);
     });
 
-    it('Submits evidence and casts votes', async () => {
-      // Open dispute and assign jurors
+    it('Cancels listed and unlisted offers, using the program ID for a missing page', async () => {
+      const [listed, unlisted] = [Keypair.generate(), Keypair.generate()];
+      await createSellerOffer(listed);
+      await createSellerOffer(unlisted);
       await program.methods
-        .openDispute("Payment issue")
+        .listOffer(0)
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          offer: offerKeypair.publicKey,
-          initiator: seller.publicKey,
-          respondent: buyer.publicKey,
-          systemProgram: SystemProgram.programId,
+          offer: listed.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
         })
-        .signers([seller, disputeKeypair])
+        .signers([seller])
         .rpc();
 
-      await program.methods
-        .assignJurors()
-        .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror1: juror1.publicKey,
-          juror2: juror2.publicKey,
-          juror3: juror3.publicKey,
-          admin: adminPda,
-          authority: admin.publicKey,
-        })
-        .signers([admin])
-        .rpc();
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
 
-      // Submit evidence
-      await program.methods
-        .submitEvidence("https://evidence.example.com/seller-proof")
+      const signature = await program.methods
+        .batchCancelOffers()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          submitter: seller.publicKey,
+          seller: seller.publicKey,
+          systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts([
+          { pubkey: listed.publicKey, isWritable: true, isSigner: false },
+          { pubkey: offerEscrowPda(listed.publicKey), isWritable: true, isSigner: false },
+          { pubkey: orderBookPagePda, isWritable: true, isSigner: false },
+          { pubkey: unlisted.publicKey, isWritable: true, isSigner: false },
+          { pubkey: offerEscrowPda(unlisted.publicKey), isWritable: true, isSigner: false },
+          { pubkey: program.programId, isWritable: false, isSigner: false },
+        ])
         .signers([seller])
-        .rpc();
+        .rpc({ commitment: 'confirmed' });
 
-      await program.methods
-        .submitEvidence("https://evidence.example.com/buyer-proof")
-        .accounts({
-          dispute: disputeKeypair.publicKey,
-          submitter: buyer.publicKey,
-        })
-        .signers([buyer])
-        .rpc();
+      const results = await batchResults(signature);
+      expect(results.map((result) => result.success)).to.deep.equal([true, true]);
 
-      // Cast votes
-      const [vote1Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), disputeKeypair.publicKey.toBuffer(), juror1.publicKey.toBuffer()],
-        program.programId
-      );
+      for (const offer of [listed, unlisted]) {
+        const offerAccount = await program.account.offer.fetch(offer.publicKey);
+        expect(offerAccount.status).to.equal(7); // Cancelled status
+      }
+      expect(await listedOffers()).to.not.include(listed.publicKey.toString());
 
-      const [vote2Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), disputeKeypair.publicKey.toBuffer(), juror2.publicKey.toBuffer()],
-        program.programId
-      );
+      // Both escrows were refunded, less the transaction fee
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.be.greaterThan(2 * LAMPORTS_PER_SOL - 10_000);
+    });
+
+    it('Rejects malformed batches', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+
+      try {
+        await program.methods
+          .batchListOffers()
+          .accounts({
+            seller: seller.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(pageAccounts([offer]).slice(0, 1))
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidBatchAccounts");
+      }
+    });
 
+    it('Cancels a listed offer and refunds its escrow', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
       await program.methods
-        .castVote(true) // Vote for buyer
+        .listOffer(0)
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror: juror1.publicKey,
-          vote: vote1Pda,
-          systemProgram: SystemProgram.programId,
+          offer: offer.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
         })
-        .signers([juror1])
+        .signers([seller])
         .rpc();
 
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+
       await program.methods
-        .castVote(true) // Vote for buyer
+        .cancelOffer()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror: juror2.publicKey,
-          vote: vote2Pda,
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+          escrowAccount: offerEscrowPda(offer.publicKey),
+          orderBookPage: orderBookPagePda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([juror2])
+        .signers([seller])
         .rpc();
 
-      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
-      expect(disputeAccount.status).to.equal(3); // VerdictReached status
-      expect(disputeAccount.votesForBuyer).to.equal(2);
-      expect(disputeAccount.votesForSeller).to.equal(0);
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(7); // Cancelled status
+      expect(await listedOffers()).to.not.include(offer.publicKey.toString());
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.be.greaterThan(LAMPORTS_PER_SOL - 10_000);
     });
 
-    it('Executes verdict and transfers funds', async () => {
-      // Setup dispute with votes
+    it("Rejects cancelling another seller's offer", async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+
+      try {
+        await program.methods
+          .cancelOffer()
+          .accounts({
+            offer: offer.publicKey,
+            seller: buyer.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            orderBookPage: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ConstraintRaw");
+      }
+
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(0); // Still Created
+    });
+  });
+
+  describe('Unlisting and Updating Offers', () => {
+    const listSellerOffer = async (offer: Keypair) => {
       await program.methods
-        .openDispute("Payment issue")
+        .listOffer(0)
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          offer: offerKeypair.publicKey,
-          initiator: seller.publicKey,
-          respondent: buyer.publicKey,
-          systemProgram: SystemProgram.programId,
+          offer: offer.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
         })
-        .signers([seller, disputeKeypair])
+        .signers([seller])
         .rpc();
+    };
+
+    it('Unlists an offer and relists it later', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+      await listSellerOffer(offer);
+      const escrowBalance = await provider.connection.getBalance(offerEscrowPda(offer.publicKey));
 
       await program.methods
-        .assignJurors()
+        .unlistOffer()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror1: juror1.publicKey,
-          juror2: juror2.publicKey,
-          juror3: juror3.publicKey,
-          admin: adminPda,
-          authority: admin.publicKey,
+          offer: offer.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
         })
-        .signers([admin])
+        .signers([seller])
         .rpc();
 
-      // Cast majority votes for buyer
-      const [vote1Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), disputeKeypair.publicKey.toBuffer(), juror1.publicKey.toBuffer()],
-        program.programId
-      );
+      let offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(8); // Unlisted status
+      expect(offerAccount.orderBookPage).to.be.null;
+      expect(await listedOffers()).to.not.include(offer.publicKey.toString());
+      expect(await provider.connection.getBalance(offerEscrowPda(offer.publicKey))).to.equal(escrowBalance);
 
-      const [vote2Pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vote"), disputeKeypair.publicKey.toBuffer(), juror2.publicKey.toBuffer()],
-        program.programId
-      );
+      await listSellerOffer(offer);
 
-      await program.methods
-        .castVote(true)
-        .accounts({
-          dispute: disputeKeypair.publicKey,
-          juror: juror1.publicKey,
-          vote: vote1Pda,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([juror1])
-        .rpc();
+      offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.status).to.equal(1); // Listed status
+      expect(offerAccount.orderBookPage).to.equal(0);
+      expect(await listedOffers()).to.include(offer.publicKey.toString());
+    });
 
-      await program.methods
-        .castVote(true)
-        .accounts({
+    it('Rejects unlisting an offer that is not listed', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+
+      try {
+        await program.methods
+          .unlistOffer()
+          .accounts({
+            offer: offer.publicKey,
+            orderBookPage: orderBookPagePda,
+            seller: seller.publicKey,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidOfferStatus");
+      }
+    });
+
+    it('Rejects accepting an unlisted offer', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+      await listSellerOffer(offer);
+      await program.methods
+        .unlistOffer()
+        .accounts({
+          offer: offer.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      try {
+        await program.methods
+          .acceptOffer(
+            new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+            new anchor.BN(1000),
+            0
+          )
+          .accounts({
+            offer: offer.publicKey,
+            buyer: buyer.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            orderBookPage: orderBookPagePda,
+            buyerReputation: reputationPda(buyer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidOfferStatus");
+      }
+    });
+
+    it('Tops up the escrow when the offer amount grows', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+      await listSellerOffer(offer);
+      const escrow = offerEscrowPda(offer.publicKey);
+      const escrowBefore = await provider.connection.getBalance(escrow);
+
+      await program.methods
+        .updateOffer(new anchor.BN(1.5 * LAMPORTS_PER_SOL), new anchor.BN(1500), "Wise")
+        .accounts({
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+          escrowAccount: escrow,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.amount.toNumber()).to.equal(1.5 * LAMPORTS_PER_SOL);
+      expect(offerAccount.fiatAmount.toNumber()).to.equal(1500);
+      expect(offerAccount.paymentMethod).to.equal("Wise");
+      expect(offerAccount.status).to.equal(1); // Still listed
+      expect(await provider.connection.getBalance(escrow)).to.equal(escrowBefore + 0.5 * LAMPORTS_PER_SOL);
+    });
+
+    it('Returns escrow to the seller when the offer amount shrinks', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+      const escrow = offerEscrowPda(offer.publicKey);
+      const escrowBefore = await provider.connection.getBalance(escrow);
+      const sellerBefore = await provider.connection.getBalance(seller.publicKey);
+
+      await program.methods
+        .updateOffer(new anchor.BN(0.25 * LAMPORTS_PER_SOL), new anchor.BN(250), "Bank Transfer")
+        .accounts({
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+          escrowAccount: escrow,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.amount.toNumber()).to.equal(0.25 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(escrow)).to.equal(escrowBefore - 0.75 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(seller.publicKey)).to.equal(sellerBefore + 0.75 * LAMPORTS_PER_SOL);
+    });
+
+    it('Rejects updates from another seller', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+
+      try {
+        await program.methods
+          .updateOffer(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(1), "Bank Transfer")
+          .accounts({
+            offer: offer.publicKey,
+            seller: buyer.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ConstraintRaw");
+      }
+    });
+
+    it('Rejects a zero amount update', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+
+      try {
+        await program.methods
+          .updateOffer(new anchor.BN(0), new anchor.BN(1000), "Bank Transfer")
+          .accounts({
+            offer: offer.publicKey,
+            seller: seller.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAmount");
+      }
+    });
+
+    it('Bumps the terms version and rejects accepting stale terms', async () => {
+      const offer = Keypair.generate();
+      await createSellerOffer(offer);
+      await listSellerOffer(offer);
+
+      await program.methods
+        .updateOffer(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(1200), "Bank Transfer")
+        .accounts({
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+          escrowAccount: offerEscrowPda(offer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      const offerAccount = await program.account.offer.fetch(offer.publicKey);
+      expect(offerAccount.termsVersion).to.equal(1);
+
+      // Buyer still holds the terms from before the update
+      try {
+        await program.methods
+          .acceptOffer(
+            new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            new anchor.BN(LAMPORTS_PER_SOL),
+            new anchor.BN(1000),
+            0
+          )
+          .accounts({
+            offer: offer.publicKey,
+            buyer: buyer.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            orderBookPage: orderBookPagePda,
+            buyerReputation: reputationPda(buyer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OfferTermsChanged");
+      }
+
+      // Same fiat amount but an old version is still stale
+      try {
+        await program.methods
+          .acceptOffer(
+            new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            new anchor.BN(LAMPORTS_PER_SOL),
+            new anchor.BN(1200),
+            0
+          )
+          .accounts({
+            offer: offer.publicKey,
+            buyer: buyer.publicKey,
+            escrowAccount: offerEscrowPda(offer.publicKey),
+            orderBookPage: orderBookPagePda,
+            buyerReputation: reputationPda(buyer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OfferTermsChanged");
+      }
+
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(LAMPORTS_PER_SOL),
+          new anchor.BN(1200),
+          1
+        )
+        .accounts({
+          offer: offer.publicKey,
+          buyer: buyer.publicKey,
+          escrowAccount: offerEscrowPda(offer.publicKey),
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      expect((await program.account.offer.fetch(offer.publicKey)).status).to.equal(2); // Accepted status
+    });
+  });
+
+  describe('Dispute Resolution Workflow', () => {
+    // The shared registry holds 64 jurors, so the same panels serve every test here
+    const panel = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    const appealPanel = [...Array(5)].map(() => Keypair.generate());
+
+    beforeEach(async () => {
+      // Initialize admin and create accepted offer
+      await program.methods
+        .initializeAdmin()
+        .accounts({
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      // Disputes need the panel tiers and the staked juror registry
+      await ensureDisputeSetup();
+      await ensureCooldownConfig();
+
+      [juror1, juror2, juror3] = panel;
+      await registerJurors([...panel, ...appealPanel]);
+
+      await program.methods
+        .createOffer(
+          new anchor.BN(LAMPORTS_PER_SOL),
+          new anchor.BN(1000),
+          "USD",
+          "Bank Transfer",
+          new anchor.BN(Math.floor(Date.now() / 1000))
+        )
+        .accounts({
+          offer: offerKeypair.publicKey,
+          seller: seller.publicKey,
+          escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, offerKeypair])
+        .rpc();
+
+      await program.methods
+        .listOffer(0)
+        .accounts({
+          offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
+        .accounts({
+          offer: offerKeypair.publicKey,
+          buyer: buyer.publicKey,
+          escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+    });
+
+    // Every juror commits a hidden buyer allocation, then the first `revealed` of them reveal
+    const commitAndRevealVotes = async (
+      buyerPercentages: number[],
+      jurors: Keypair[] = [juror1, juror2, juror3],
+      revealed: number = jurors.length
+    ) => {
+      // Votes open once the evidence phase is over
+      await waitOut(disputeTimings.evidencePeriod);
+
+      const salts = jurors.map(() => randomBytes(32));
+
+      for (const [i, juror] of jurors.entries()) {
+        await program.methods
+          .commitVote(voteCommitment(disputeKeypair.publicKey, juror.publicKey, buyerPercentages[i], salts[i]))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror])
+          .rpc();
+      }
+
+      // Reveals open once every juror committed
+      for (const [i, juror] of jurors.slice(0, revealed).entries()) {
+        await program.methods
+          .revealVote(buyerPercentages[i], Array.from(salts[i]))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+          })
+          .signers([juror])
+          .rpc();
+      }
+    };
+
+    // Opens a dispute on the accepted offer, matches the fee and seats the first panel
+    const openDisputeWithPanel = async () => {
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+    };
+
+    const appeal = (appellant: Keypair) => program.methods
+      .appealVerdict()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        offer: offerKeypair.publicKey,
+        disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+        appellant: appellant.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([appellant])
+      .rpc();
+
+    // Execution is permissionless, so a fresh keypair cranks it by default
+    const executeVerdict = async (caller: Keypair = Keypair.generate()) => program.methods
+      .executeVerdict()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        offer: offerKeypair.publicKey,
+        escrowAccount: escrowPda,
+        disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        buyerReputation: reputationPda(buyer.publicKey),
+        sellerReputation: reputationPda(seller.publicKey),
+        jurorRegistry: jurorRegistryPda,
+        rewardToken: rewardTokenPda,
+        caller: caller.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(await verdictJurorAccounts(disputeKeypair.publicKey))
+      .signers([caller])
+      .rpc();
+
+    const resolveExpiredDispute = () => program.methods
+      .resolveExpiredDispute()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        offer: offerKeypair.publicKey,
+        escrowAccount: escrowPda,
+        disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        buyerReputation: reputationPda(buyer.publicKey),
+        sellerReputation: reputationPda(seller.publicKey),
+        caller: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const settle = (juror: Keypair) => program.methods
+      .settleJuror()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        jurorRegistry: jurorRegistryPda,
+        jurorStake: jurorStakePda(juror.publicKey),
+        vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const proposeSettlement = (proposer: Keypair, buyerAmount: number) => program.methods
+      .proposeSettlement(new anchor.BN(buyerAmount))
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        offer: offerKeypair.publicKey,
+        proposer: proposer.publicKey,
+      })
+      .signers([proposer])
+      .rpc();
+
+    const acceptSettlement = (accepter: Keypair, expectedBuyerAmount: number) => program.methods
+      .acceptSettlement(new anchor.BN(expectedBuyerAmount))
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        offer: offerKeypair.publicKey,
+        escrowAccount: escrowPda,
+        disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        buyerReputation: reputationPda(buyer.publicKey),
+        sellerReputation: reputationPda(seller.publicKey),
+        accepter: accepter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([accepter])
+      .rpc();
+
+    const overrideRecordPda = (dispute: PublicKey) => PublicKey.findProgramAddressSync(
+      [Buffer.from("override_record"), dispute.toBuffer()],
+      program.programId
+    )[0];
+
+    // Signing secondary admin authorities follow the named accounts
+    const adminSignerAccounts = (signers: Keypair[]) =>
+      signers.map((signer) => ({ pubkey: signer.publicKey, isSigner: true, isWritable: false }));
+
+    const proposeForceResolve = (buyerPercentage: number, secondarySigners: Keypair[] = []) => program.methods
+      .proposeForceResolve(buyerPercentage, Array.from(createHash('sha256').update("override-justification").digest()))
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        overrideRecord: overrideRecordPda(disputeKeypair.publicKey),
+        admin: adminPda,
+        authority: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(adminSignerAccounts(secondarySigners))
+      .signers([admin, ...secondarySigners])
+      .rpc();
+
+    const cancelForceResolve = () => program.methods
+      .cancelForceResolve()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        overrideRecord: overrideRecordPda(disputeKeypair.publicKey),
+        admin: adminPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const forceResolve = () => program.methods
+      .forceResolve()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        overrideRecord: overrideRecordPda(disputeKeypair.publicKey),
+        offer: offerKeypair.publicKey,
+        escrowAccount: escrowPda,
+        disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        buyerReputation: reputationPda(buyer.publicKey),
+        sellerReputation: reputationPda(seller.publicKey),
+        admin: adminPda,
+        authority: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const updateAdminAuthorities = (secondaryAuthorities: PublicKey[], requiredSignatures: number) => program.methods
+      .updateAdminAuthorities(secondaryAuthorities, requiredSignatures)
+      .accounts({
+        admin: adminPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    it('Opens a dispute', async () => {
+      const reason = "Buyer did not send fiat payment";
+
+      await program.methods
+        .openDispute(reason)
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+
+      expect(disputeAccount.initiator.toString()).to.equal(seller.publicKey.toString());
+      expect(disputeAccount.respondent.toString()).to.equal(buyer.publicKey.toString());
+      expect(disputeAccount.reason).to.equal(reason);
+      expect(disputeAccount.status).to.equal(0); // Opened status
+      expect(offerAccount.status).to.equal(5); // DisputeOpened status
+    });
+
+    it('Assigns jurors to dispute', async () => {
+      // Open dispute first
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      // Jurors are only assigned once the respondent matched the dispute fee
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      // A juror cannot fill two seats
+      try {
+        await program.methods
+          .assignJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .remainingAccounts(jurorStakeAccounts([juror1, juror1, juror2]))
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("DuplicateJuror");
+      }
+
+      // Assign jurors
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
+      expect(disputeAccount.jurors[0].toString()).to.equal(juror1.publicKey.toString());
+      expect(disputeAccount.jurors[1].toString()).to.equal(juror2.publicKey.toString());
+      expect(disputeAccount.jurors[2].toString()).to.equal(juror3.publicKey.toString());
+    });
+
+    it('Submits evidence, then commits and reveals votes', async () => {
+      // Open dispute and assign jurors
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      // Jurors are only assigned once the respondent matched the dispute fee
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+
+      // Submit evidence
+      await program.methods
+        .submitEvidence(
+          Array.from(createHash('sha256').update("seller-proof").digest()),
+          "https://evidence.example.com/seller-proof",
+          "application/pdf"
+        )
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          evidence: evidencePda(disputeKeypair.publicKey, 0),
+          submitter: seller.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .submitEvidence(
+          Array.from(createHash('sha256').update("buyer-proof").digest()),
+          "https://evidence.example.com/buyer-proof",
+          "application/pdf"
+        )
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          evidence: evidencePda(disputeKeypair.publicKey, 1),
+          submitter: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      // Evidence is tagged by trade role; the seller opened this dispute
+      const sellerEvidence = await program.account.evidence.fetch(evidencePda(disputeKeypair.publicKey, 0));
+      const buyerEvidence = await program.account.evidence.fetch(evidencePda(disputeKeypair.publicKey, 1));
+      expect(sellerEvidence.role).to.equal(1); // Seller
+      expect(buyerEvidence.role).to.equal(0); // Buyer
+      expect(Buffer.from(buyerEvidence.contentHash)).to.deep.equal(createHash('sha256').update("buyer-proof").digest());
+      expect((await program.account.dispute.fetch(disputeKeypair.publicKey)).initiatorRole).to.equal(1);
+
+      // Commit and reveal votes: each juror awards a share of the escrow to the buyer
+      await commitAndRevealVotes([100, 100, 0]);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(4); // VerdictReached status
+      expect(disputeAccount.votesCommitted).to.equal(3);
+      expect(disputeAccount.buyerAllocations).to.deep.equal([100, 100, 0]);
+    });
+
+    it('Rejects reveals until every juror committed, and mismatched reveals', async () => {
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      // Jurors are only assigned once the respondent matched the dispute fee
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+
+      const salts = [juror1, juror2, juror3].map(() => randomBytes(32));
+      const commit = (juror: Keypair, buyerPercentage: number, salt: Buffer) => program.methods
+        .commitVote(voteCommitment(disputeKeypair.publicKey, juror.publicKey, buyerPercentage, salt))
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          juror: juror.publicKey,
+          vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([juror])
+        .rpc();
+      const reveal = (juror: Keypair, buyerPercentage: number, salt: Buffer) => program.methods
+        .revealVote(buyerPercentage, Array.from(salt))
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          juror: juror.publicKey,
+          vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+        })
+        .signers([juror])
+        .rpc();
+
+      // Votes open once the evidence phase is over, which also closes evidence submission
+      try {
+        await commit(juror1, 70, salts[0]);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("VotingNotOpen");
+      }
+
+      await waitOut(disputeTimings.evidencePeriod);
+
+      try {
+        await program.methods
+          .submitEvidence(
+            Array.from(createHash('sha256').update("late-proof").digest()),
+            "https://evidence.example.com/late-proof",
+            "application/pdf"
+          )
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            offer: offerKeypair.publicKey,
+            evidence: evidencePda(disputeKeypair.publicKey, 0),
+            submitter: seller.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("EvidencePhaseClosed");
+      }
+
+      await commit(juror1, 70, salts[0]);
+      await commit(juror2, 150, salts[1]);
+
+      // One juror is still deciding, so nothing can be revealed yet
+      try {
+        await reveal(juror1, 70, salts[0]);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("RevealNotOpen");
+      }
+
+      await commit(juror3, 70, salts[2]);
+
+      // Revealing a different allocation than the one committed
+      try {
+        await reveal(juror1, 30, salts[0]);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidVoteReveal");
+      }
+
+      // Allocations above 100 percent are rejected even when committed
+      try {
+        await reveal(juror2, 150, salts[1]);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAllocation");
+      }
+
+      await reveal(juror1, 70, salts[0]);
+
+      const vote = await program.account.vote.fetch(votePda(disputeKeypair.publicKey, juror1.publicKey));
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(vote.revealed).to.be.true;
+      expect(vote.buyerPercentage).to.equal(70);
+      expect(disputeAccount.buyerAllocations).to.deep.equal([70]);
+    });
+
+    it('Extends the evidence and voting phases once on a panel majority', async () => {
+      await openDisputeWithPanel();
+
+      const requestExtension = (requester: Keypair) => program.methods
+        .requestPhaseExtension()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          requester: requester.publicKey,
+        })
+        .signers([requester])
+        .rpc();
+      const approveExtension = (juror: Keypair) => program.methods
+        .approvePhaseExtension()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          juror: juror.publicKey,
+        })
+        .signers([juror])
+        .rpc();
+
+      try {
+        await approveExtension(juror1);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingExtension");
+      }
+
+      // Only the parties may ask for more time
+      try {
+        await requestExtension(admin);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      const before = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      await requestExtension(buyer);
+
+      await approveExtension(juror1);
+      try {
+        await approveExtension(juror1);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ExtensionAlreadyApproved");
+      }
+
+      // Approval is up to the panel, not the admin
+      try {
+        await approveExtension(admin);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NotAJuror");
+      }
+
+      let disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.extensionGranted).to.equal(false);
+      expect(disputeAccount.evidenceEndsAt.toNumber()).to.equal(before.evidenceEndsAt.toNumber());
+
+      // A second approval is a majority of the three jurors and shifts both deadlines by 48 hours
+      await approveExtension(juror2);
+
+      disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.extensionGranted).to.equal(true);
+      expect(disputeAccount.extensionRequestedBy.toString()).to.equal(buyer.publicKey.toString());
+      expect(disputeAccount.evidenceEndsAt.sub(before.evidenceEndsAt).toNumber()).to.equal(172800);
+      expect(disputeAccount.votingEndsAt.sub(before.votingEndsAt).toNumber()).to.equal(172800);
+
+      // The extension can be used only once per dispute
+      try {
+        await requestExtension(seller);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ExtensionAlreadyRequested");
+      }
+
+      try {
+        await approveExtension(juror3);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingExtension");
+      }
+    });
+
+    it('Rejects a reveal without a prior commit', async () => {
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .revealVote(100, Array.from(randomBytes(32)))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror1.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror1.publicKey),
+          })
+          .signers([juror1])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+    });
+
+    it('Executes verdict and transfers funds', async () => {
+      // Setup dispute with votes
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      // Jurors are only assigned once the respondent matched the dispute fee
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+
+      // Majority of the panel awards the escrow to the buyer
+      await commitAndRevealVotes([100, 100, 0]);
+
+      // Nobody appeals in time
+      await waitOut(disputeTimings.appealWindow);
+
+      // Execute verdict; anyone can crank it, not just the admin
+      const caller = Keypair.generate();
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      await executeVerdict(caller);
+
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(offerAccount.status).to.equal(6); // Completed status
+      expect(buyerBalanceAfter).to.be.greaterThan(buyerBalanceBefore);
+      expect(await provider.connection.getBalance(caller.publicKey)).to.equal(0);
+    });
+
+    it('Rejects verdict execution by any caller before the panel decided', async () => {
+      await openDisputeWithPanel();
+
+      // A third party passes account validation, but no verdict exists yet
+      try {
+        await executeVerdict(Keypair.generate());
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeStatus");
+      }
+
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      expect(offerAccount.status).to.equal(5); // DisputeOpened status
+    });
+
+    it('Splits the escrow by the median allocation', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([70, 60, 20]);
+      await waitOut(disputeTimings.appealWindow);
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      const jurorBalancesBefore = await Promise.all(
+        [juror1, juror2, juror3].map((juror) => provider.connection.getBalance(juror.publicKey))
+      );
+
+      await executeVerdict();
+
+      // Median 60%: the buyer gets 60% of the 1 SOL escrow and of the 0.1 SOL security bond,
+      // and each party gets back the share of its dispute fee it did not lose
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(buyerBalanceAfter - buyerBalanceBefore).to.equal(0.66 * LAMPORTS_PER_SOL + 0.6 * disputeFee);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(0.44 * LAMPORTS_PER_SOL + 0.4 * disputeFee);
+
+      // The lost shares pay the majority jurors; the 20% vote is in the minority and gets nothing
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.jurorFeePool.toNumber()).to.equal(disputeFee);
+      const jurorBalancesAfter = await Promise.all(
+        [juror1, juror2, juror3].map((juror) => provider.connection.getBalance(juror.publicKey))
+      );
+      expect(jurorBalancesAfter[0] - jurorBalancesBefore[0]).to.equal(disputeFee / 2);
+      expect(jurorBalancesAfter[1] - jurorBalancesBefore[1]).to.equal(disputeFee / 2);
+      expect(jurorBalancesAfter[2]).to.equal(jurorBalancesBefore[2]);
+    });
+
+    it('Records the verdict on both parties\' reputations', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 80, 0]);
+      await waitOut(disputeTimings.appealWindow);
+      await executeVerdict();
+
+      // The buyer was awarded the larger share
+      const buyerReputation = await program.account.reputation.fetch(reputationPda(buyer.publicKey));
+      const sellerReputation = await program.account.reputation.fetch(reputationPda(seller.publicKey));
+      expect(buyerReputation.disputedTrades).to.equal(1);
+      expect(buyerReputation.disputesWon).to.equal(1);
+      expect(buyerReputation.disputesLost).to.equal(0);
+      expect(sellerReputation.disputedTrades).to.equal(1);
+      expect(sellerReputation.disputesWon).to.equal(0);
+      expect(sellerReputation.disputesLost).to.equal(1);
+      expect(sellerReputation.rating).to.be.lessThan(buyerReputation.rating);
+    });
+
+    it('Rejects executing a verdict while it can still be appealed', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0]);
+
+      try {
+        await executeVerdict();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AppealWindowOpen");
+      }
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(4); // VerdictReached status
+    });
+
+    it('Appeals a verdict to a larger panel against a bond', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0]);
+
+      // Only the losing party may appeal
+      try {
+        await appeal(buyer);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await appeal(seller);
+
+      // Bond is 10% of the 1 SOL trade
+      const escrow = await program.account.disputeEscrow.fetch(disputeEscrowPda(disputeKeypair.publicKey));
+      expect(escrow.appellant.toString()).to.equal(seller.publicKey.toString());
+      expect(escrow.appealBond.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(sellerBalanceBefore - sellerBalanceAfter).to.be.at.least(0.1 * LAMPORTS_PER_SOL);
+
+      // A fresh round starts with a five-juror panel
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(0); // Opened status
+      expect(disputeAccount.appealRound).to.equal(1);
+      expect(disputeAccount.panelSize).to.equal(5);
+      expect(disputeAccount.appellant.toString()).to.equal(seller.publicKey.toString());
+      expect(disputeAccount.buyerAllocations).to.be.empty;
+      expect(disputeAccount.roundVerdicts[0].buyerPercentage).to.equal(100);
+    });
+
+    it('Rejects an appeal after the appeal window', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0]);
+      await waitOut(disputeTimings.appealWindow);
+
+      try {
+        await appeal(seller);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AppealWindowClosed");
+      }
+    });
+
+    it('Allows a single appeal and refunds the bond when the appeal succeeds', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0]);
+      await appeal(seller);
+
+      // Jurors of the first round cannot sit on the appeal panel
+      try {
+        await program.methods
+          .assignJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .remainingAccounts(jurorStakeAccounts([juror1, ...appealPanel.slice(1)]))
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("JurorConflictOfInterest");
+      }
+
+      // The appeal panel sides with the seller
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts(appealPanel))
+        .signers([admin])
+        .rpc();
+      await commitAndRevealVotes([0, 0, 0, 100, 100], appealPanel);
+
+      // The appeal round was the last one
+      try {
+        await appeal(buyer);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AppealNotAvailable");
+      }
+
+      // No appeal window is left, so the verdict executes at once
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await executeVerdict();
+
+      // Escrow, security bond, the refunded appeal bond and the seller's own fee go to the seller
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(1.2 * LAMPORTS_PER_SOL + disputeFee);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(disputeAccount.buyerAllocations).to.deep.equal([0, 0, 0, 100, 100]);
+    });
+
+    it('Awards an uncontested dispute to the initiator once the response window closes', async () => {
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+
+      const claimDefaultJudgment = () => program.methods
+        .claimDefaultJudgment()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          escrowAccount: escrowPda,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          buyer: buyer.publicKey,
+          seller: seller.publicKey,
+          buyerReputation: reputationPda(buyer.publicKey),
+          sellerReputation: reputationPda(seller.publicKey),
+          caller: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // No panel is seated for an uncontested dispute
+      try {
+        await program.methods
+          .assignJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .remainingAccounts(jurorStakeAccounts())
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("RespondentFeeUnpaid");
+      }
+
+      // The respondent can still match the fee
+      try {
+        await claimDefaultJudgment();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ResponseWindowOpen");
+      }
+
+      await waitOut(disputeTimings.responsePeriod);
+
+      try {
+        await payDisputeFee(disputeKeypair.publicKey, buyer);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ResponseWindowClosed");
+      }
+
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await claimDefaultJudgment();
+
+      // The seller initiated, so escrow, security bond and the seller's own fee go to the seller
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(1.1 * LAMPORTS_PER_SOL + disputeFee);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(offerAccount.status).to.equal(6); // Completed status
+
+      try {
+        await claimDefaultJudgment();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeStatus");
+      }
+    });
+
+    it('Refunds both parties when a dispute expires without a quorum', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([0, 100, 100], [juror1, juror2, juror3], 1);
+
+      try {
+        await resolveExpiredDispute();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("DisputeNotExpired");
+      }
+
+      await waitOut(roundDuration);
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await resolveExpiredDispute();
+
+      // The lone reveal decides nothing: the seller gets the escrow and the buyer the security bond,
+      // and both dispute fees are refunded
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(buyerBalanceAfter - buyerBalanceBefore).to.equal(0.1 * LAMPORTS_PER_SOL + disputeFee);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(LAMPORTS_PER_SOL + disputeFee);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(disputeAccount.jurorFeePool.toNumber()).to.equal(0);
+
+      try {
+        await executeVerdict();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeStatus");
+      }
+    });
+
+    it('Rejects resolving an expired dispute whose reveals reached the quorum', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0], [juror1, juror2, juror3], 2);
+      await waitOut(roundDuration);
+
+      try {
+        await resolveExpiredDispute();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeStatus");
+      }
+
+      // The quorum's verdict is executed instead
+      await waitOut(disputeTimings.appealWindow);
+      await executeVerdict();
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+    });
+
+    it('Refunds the appeal bond when the appeal round misses its quorum', async () => {
+      await openDisputeWithPanel();
+      await commitAndRevealVotes([100, 100, 0]);
+      await appeal(seller);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts(appealPanel))
+        .signers([admin])
+        .rpc();
+
+      // Two of five reveals fall short of the appeal panel's quorum
+      await commitAndRevealVotes([0, 100, 0, 0, 0], appealPanel, 2);
+      await waitOut(roundDuration);
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await resolveExpiredDispute();
+
+      // The appealed verdict stands, the seller gets the appeal bond back and both fees are refunded
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(buyerBalanceAfter - buyerBalanceBefore).to.equal(1.1 * LAMPORTS_PER_SOL + disputeFee);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(0.1 * LAMPORTS_PER_SOL + disputeFee);
+
+      // Without a verdict for the round, neither revealed vote counts as a minority vote or earns a fee
+      for (const juror of appealPanel.slice(0, 2)) {
+        const jurorBalanceBefore = await provider.connection.getBalance(juror.publicKey);
+        await settle(juror);
+        const jurorStake = await program.account.jurorStake.fetch(jurorStakePda(juror.publicKey));
+        expect(jurorStake.minorityVotes).to.equal(0);
+        expect(await provider.connection.getBalance(juror.publicKey)).to.equal(jurorBalanceBefore);
+      }
+    });
+
+    it('Settles a dispute on the split both parties agreed to', async () => {
+      await openDisputeWithPanel();
+      await proposeSettlement(buyer, 0.7 * LAMPORTS_PER_SOL);
+
+      let disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.settlementProposer.toString()).to.equal(buyer.publicKey.toString());
+      expect(disputeAccount.settlementBuyerAmount.toNumber()).to.equal(0.7 * LAMPORTS_PER_SOL);
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await acceptSettlement(seller, 0.7 * LAMPORTS_PER_SOL);
+
+      // The seller gets the rest of the 1.1 SOL escrow and no juror fee is owed, so both fees are refunded
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(buyerBalanceAfter - buyerBalanceBefore).to.equal(0.7 * LAMPORTS_PER_SOL + disputeFee);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(0.4 * LAMPORTS_PER_SOL + disputeFee);
+
+      disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(disputeAccount.settledByParties).to.be.true;
+      expect(disputeAccount.jurorFeePool.toNumber()).to.equal(0);
+      expect(offerAccount.status).to.equal(6); // Completed status
+
+      // The panel is released without judging votes
+      const jurorStakeBefore = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      await settle(juror1);
+      const jurorStakeAfter = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      expect(jurorStakeAfter.activeDisputes).to.equal(jurorStakeBefore.activeDisputes - 1);
+      expect(jurorStakeAfter.missedVotes).to.equal(jurorStakeBefore.missedVotes);
+    });
+
+    it('Rejects a proposer accepting their own settlement', async () => {
+      await openDisputeWithPanel();
+
+      try {
+        await acceptSettlement(seller, 0);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingSettlement");
+      }
+
+      await proposeSettlement(buyer, 0.7 * LAMPORTS_PER_SOL);
+
+      try {
+        await acceptSettlement(buyer, 0.7 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      // A replaced proposal cannot be accepted on its old terms
+      await proposeSettlement(buyer, 0.9 * LAMPORTS_PER_SOL);
+
+      try {
+        await acceptSettlement(seller, 0.7 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SettlementTermsChanged");
+      }
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
+    });
+
+    it('Force-resolves a dispute once the override timelock has elapsed', async () => {
+      await openDisputeWithPanel();
+      await proposeForceResolve(70);
+
+      let overrideRecord = await program.account.overrideRecord.fetch(overrideRecordPda(disputeKeypair.publicKey));
+      expect(overrideRecord.buyerPercentage).to.equal(70);
+      expect(overrideRecord.proposedBy.map((key) => key.toString())).to.deep.equal([admin.publicKey.toString()]);
+      expect(overrideRecord.executedAt.toNumber()).to.equal(0);
+
+      try {
+        await forceResolve();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OverrideTimelockActive");
+      }
+
+      await waitOut(disputeTimings.overrideTimelock);
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      await forceResolve();
+
+      // 70% of the 1.1 SOL escrow goes to the buyer, and the panel's work is set aside so both fees are refunded
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      expect(buyerBalanceAfter - buyerBalanceBefore).to.equal(0.77 * LAMPORTS_PER_SOL + disputeFee);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.equal(0.33 * LAMPORTS_PER_SOL + disputeFee);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(5); // Resolved status
+      expect(disputeAccount.overridden).to.be.true;
+      expect(offerAccount.status).to.equal(6); // Completed status
+
+      // The executed record is permanent
+      overrideRecord = await program.account.overrideRecord.fetch(overrideRecordPda(disputeKeypair.publicKey));
+      expect(overrideRecord.executedAt.toNumber()).to.be.greaterThan(0);
+      expect(overrideRecord.executedBy.map((key) => key.toString())).to.deep.equal([admin.publicKey.toString()]);
+
+      try {
+        await cancelForceResolve();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OverrideAlreadyExecuted");
+      }
+    });
+
+    it('Cancels a proposed override and accepts a new proposal', async () => {
+      await openDisputeWithPanel();
+      await proposeForceResolve(100);
+      await cancelForceResolve();
+
+      expect(await provider.connection.getAccountInfo(overrideRecordPda(disputeKeypair.publicKey))).to.be.null;
+
+      // The cancelled proposal can no longer be executed
+      await waitOut(disputeTimings.overrideTimelock);
+      try {
+        await forceResolve();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+
+      await proposeForceResolve(30);
+      const overrideRecord = await program.account.overrideRecord.fetch(overrideRecordPda(disputeKeypair.publicKey));
+      expect(overrideRecord.buyerPercentage).to.equal(30);
+
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
+    });
+
+    it('Requires the admin multisig threshold to assign jurors and propose overrides', async () => {
+      const secondaryAdmins = [Keypair.generate(), Keypair.generate()];
+      await updateAdminAuthorities(secondaryAdmins.map((signer) => signer.publicKey), 2);
+
+      try {
+        await program.methods
+          .openDispute("Payment issue")
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            offer: offerKeypair.publicKey,
+            disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+            initiator: seller.publicKey,
+            respondent: buyer.publicKey,
+            disputeConfig: disputeConfigPda,
+            reputation: reputationPda(seller.publicKey),
+            cooldownConfig: cooldownConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller, disputeKeypair])
+          .rpc();
+        await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+        const assignJurors = (secondarySigners: Keypair[]) => program.methods
+          .assignJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .remainingAccounts([...jurorStakeAccounts(), ...adminSignerAccounts(secondarySigners)])
+          .signers([admin, ...secondarySigners])
+          .rpc();
+
+        // The primary authority alone is one signature short
+        try {
+          await assignJurors([]);
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("AdminRequired");
+        }
+
+        await assignJurors([secondaryAdmins[0]]);
+        const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+        expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
+
+        try {
+          await proposeForceResolve(50);
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("AdminRequired");
+        }
+
+        await proposeForceResolve(50, [secondaryAdmins[1]]);
+        const overrideRecord = await program.account.overrideRecord.fetch(overrideRecordPda(disputeKeypair.publicKey));
+        expect(overrideRecord.proposedBy.map((key) => key.toString())).to.deep.equal(
+          [admin.publicKey, secondaryAdmins[1].publicKey].map((key) => key.toString())
+        );
+      } finally {
+        // Later tests act with the primary authority alone
+        await updateAdminAuthorities([PublicKey.default, PublicKey.default], 1);
+      }
+    });
+  });
+
+  describe('Dispute Config', () => {
+    const defaultTiers = [{ minAmount: new anchor.BN(0), panelSize: 3 }];
+
+    const updatePanelTiers = (tiers: { minAmount: anchor.BN; panelSize: number }[]) =>
+      program.methods
+        .updatePanelTiers(tiers)
+        .accounts({
+          disputeConfig: disputeConfigPda,
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    const expectInvalidPanelConfig = async (tiers: { minAmount: anchor.BN; panelSize: number }[]) => {
+      try {
+        await updatePanelTiers(tiers);
+        expect.fail("Should have failed with invalid panel tiers");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPanelConfig");
+      }
+    };
+
+    beforeEach(async () => {
+      await program.methods
+        .initializeAdmin()
+        .accounts({
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await ensureDisputeSetup();
+    });
+
+    // Other suites expect a three-juror panel for every amount
+    afterEach(async () => {
+      await updatePanelTiers(defaultTiers);
+    });
+
+    it('Seats the panel of the tier matching the trade amount', async () => {
+      await updatePanelTiers([
+        { minAmount: new anchor.BN(0), panelSize: 3 },
+        { minAmount: new anchor.BN(0.5 * LAMPORTS_PER_SOL), panelSize: 5 },
+      ]);
+
+      const config = await program.account.disputeConfig.fetch(disputeConfigPda);
+      expect(config.panelTiers.map((tier) => tier.panelSize)).to.deep.equal([3, 5]);
+
+      await createSellerOffer(offerKeypair);
+      await program.methods
+        .listOffer(0)
+        .accounts({
+          offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
+        .accounts({
+          offer: offerKeypair.publicKey,
+          buyer: buyer.publicKey,
+          escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      // A 1 SOL trade falls in the second tier
+      const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.panelSize).to.equal(5);
+
+      // Three stakes cannot fill a five-juror panel
+      try {
+        await program.methods
+          .assignJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .remainingAccounts(jurorStakeAccounts())
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed with a short panel");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPanelSize");
+      }
+    });
+
+    it('Rejects tiers whose amounts do not ascend', async () => {
+      await expectInvalidPanelConfig([
+        { minAmount: new anchor.BN(0), panelSize: 3 },
+        { minAmount: new anchor.BN(2 * LAMPORTS_PER_SOL), panelSize: 5 },
+        { minAmount: new anchor.BN(LAMPORTS_PER_SOL), panelSize: 7 },
+      ]);
+    });
+
+    it('Rejects an empty tier list', async () => {
+      await expectInvalidPanelConfig([]);
+    });
+
+    it('Rejects more tiers than the config holds', async () => {
+      // MAX_PANEL_TIERS is 4
+      await expectInvalidPanelConfig(
+        [0, 1, 2, 3, 4].map((i) => ({ minAmount: new anchor.BN(i * LAMPORTS_PER_SOL), panelSize: 3 }))
+      );
+    });
+
+    it('Rejects even and oversized panels', async () => {
+      await expectInvalidPanelConfig([{ minAmount: new anchor.BN(0), panelSize: 4 }]);
+      await expectInvalidPanelConfig([{ minAmount: new anchor.BN(0), panelSize: 11 }]);
+    });
+
+    it('Rejects tiers that do not start at zero', async () => {
+      await expectInvalidPanelConfig([{ minAmount: new anchor.BN(LAMPORTS_PER_SOL), panelSize: 3 }]);
+    });
+
+    it('Updates the dispute phase lengths and rejects an empty phase', async () => {
+      await program.methods
+        .updateDisputeTimings(disputeTimings)
+        .accounts({
+          disputeConfig: disputeConfigPda,
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const config = await program.account.disputeConfig.fetch(disputeConfigPda);
+      expect(config.timings.appealWindow.toNumber()).to.equal(disputeTimings.appealWindow.toNumber());
+
+      try {
+        await program.methods
+          .updateDisputeTimings({ ...disputeTimings, revealPeriod: new anchor.BN(0) })
+          .accounts({
+            disputeConfig: disputeConfigPda,
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed with an empty reveal phase");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeTimings");
+      }
+    });
+  });
+
+  describe('Juror Registry', () => {
+    beforeEach(async () => {
+      await program.methods
+        .initializeAdmin()
+        .accounts({
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await ensureDisputeSetup();
+
+      for (const juror of [juror1, juror2, juror3]) {
+        await program.methods
+          .registerJuror(new anchor.BN(LAMPORTS_PER_SOL))
+          .accounts({
+            jurorRegistry: jurorRegistryPda,
+            jurorStake: jurorStakePda(juror.publicKey),
+            juror: juror.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror])
+          .rpc();
+      }
+    });
+
+    const unregister = (juror: Keypair) => program.methods
+      .unregisterJuror()
+      .accounts({
+        jurorRegistry: jurorRegistryPda,
+        jurorStake: jurorStakePda(juror.publicKey),
+        juror: juror.publicKey,
+      })
+      .signers([juror])
+      .rpc();
+
+    const settle = (juror: Keypair) => program.methods
+      .settleJuror()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        jurorRegistry: jurorRegistryPda,
+        jurorStake: jurorStakePda(juror.publicKey),
+        vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Opens a dispute on a fresh trade and seats juror1-3
+    const openDisputeWithJurors = async () => {
+      await createSellerOffer(offerKeypair);
+      await program.methods
+        .listOffer(0)
+        .accounts({
+          offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
+        .accounts({
+          offer: offerKeypair.publicKey,
+          buyer: buyer.publicKey,
+          escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+
+      await program.methods
+        .assignJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .remainingAccounts(jurorStakeAccounts())
+        .signers([admin])
+        .rpc();
+    };
+
+    // Every juror commits; juror1 and juror2 reveal full buyer awards, juror3 never reveals
+    const revealAllButJuror3 = async () => {
+      await waitOut(disputeTimings.evidencePeriod);
+      const salts = [juror1, juror2, juror3].map(() => randomBytes(32));
+      for (const [i, juror] of [juror1, juror2, juror3].entries()) {
+        await program.methods
+          .commitVote(voteCommitment(disputeKeypair.publicKey, juror.publicKey, 100, salts[i]))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror])
+          .rpc();
+      }
+
+      for (const [i, juror] of [juror1, juror2].entries()) {
+        await program.methods
+          .revealVote(100, Array.from(salts[i]))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+          })
+          .signers([juror])
+          .rpc();
+      }
+    };
+
+    // With a reveal missing, the verdict is final once the reveal phase ends and
+    // executes once the appeal window after it closed
+    const executeAfterRevealPhase = async () => {
+      await waitOut(
+        disputeTimings.evidencePeriod
+          .add(disputeTimings.votingPeriod)
+          .add(disputeTimings.revealPeriod)
+          .add(disputeTimings.appealWindow)
+      );
+      await program.methods
+        .executeVerdict()
+        .accounts({
           dispute: disputeKeypair.publicKey,
-          juror: juror2.publicKey,
-          vote: vote2Pda,
+          offer: offerKeypair.publicKey,
+          escrowAccount: escrowPda,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          buyer: buyer.publicKey,
+          seller: seller.publicKey,
+          buyerReputation: reputationPda(buyer.publicKey),
+          sellerReputation: reputationPda(seller.publicKey),
+          jurorRegistry: jurorRegistryPda,
+          rewardToken: rewardTokenPda,
+          caller: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(await verdictJurorAccounts(disputeKeypair.publicKey))
+        .rpc();
+    };
+
+    it('Increases a juror stake', async () => {
+      const registryBefore = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+
+      await program.methods
+        .increaseJurorStake(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+        .accounts({
+          jurorRegistry: jurorRegistryPda,
+          jurorStake: jurorStakePda(juror1.publicKey),
+          juror: juror1.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([juror2])
+        .signers([juror1])
+        .rpc();
+
+      const stake = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      const registryAfter = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+      expect(stake.stake.toNumber()).to.equal(1.5 * LAMPORTS_PER_SOL);
+      expect(registryAfter.totalStaked.sub(registryBefore.totalStaked).toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods
+          .increaseJurorStake(new anchor.BN(0))
+          .accounts({
+            jurorRegistry: jurorRegistryPda,
+            jurorStake: jurorStakePda(juror1.publicKey),
+            juror: juror1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror1])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAmount");
+      }
+    });
+
+    it('Unregisters a juror and refunds the stake', async () => {
+      const balanceBefore = await provider.connection.getBalance(juror1.publicKey);
+
+      await unregister(juror1);
+
+      const registry = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+      expect(registry.jurors.map((key) => key.toString())).to.not.include(juror1.publicKey.toString());
+      expect(await provider.connection.getAccountInfo(jurorStakePda(juror1.publicKey))).to.be.null;
+      // Stake plus the account rent come back to the juror
+      expect(await provider.connection.getBalance(juror1.publicKey)).to.be.greaterThan(balanceBefore + LAMPORTS_PER_SOL);
+    });
+
+    it('Lets a suspended juror unregister', async () => {
+      await program.methods
+        .setJurorSuspension(true)
+        .accounts({
+          jurorStake: jurorStakePda(juror1.publicKey),
+          admin: adminPda,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const stake = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      expect(stake.status).to.equal(1); // Suspended status
+
+      await unregister(juror1);
+      expect(await provider.connection.getAccountInfo(jurorStakePda(juror1.publicKey))).to.be.null;
+    });
+
+    it('Rejects suspension by a non-admin', async () => {
+      try {
+        await program.methods
+          .setJurorSuspension(true)
+          .accounts({
+            jurorStake: jurorStakePda(juror1.publicKey),
+            admin: adminPda,
+            authority: seller.publicKey,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AdminRequired");
+      }
+    });
+
+    it('Slashes an unrevealed vote at settlement and sweeps the slashed stake', async () => {
+      await openDisputeWithJurors();
+
+      // Seated jurors cannot leave until they are settled
+      try {
+        await unregister(juror3);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("JurorHasActiveDisputes");
+      }
+
+      await revealAllButJuror3();
+
+      // A late reveal is still possible, so nobody is settled before the round is over
+      try {
+        await settle(juror3);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidDisputeStatus");
+      }
+
+      await executeAfterRevealPhase();
+
+      const registryBefore = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+      await settle(juror3);
+      await settle(juror1);
+
+      const unrevealed = await program.account.jurorStake.fetch(jurorStakePda(juror3.publicKey));
+      expect(unrevealed.stake.toNumber()).to.equal(0.9 * LAMPORTS_PER_SOL); // 10% slash
+      expect(unrevealed.missedVotes).to.equal(1);
+      expect(unrevealed.slashCount).to.equal(1);
+      expect(unrevealed.activeDisputes).to.equal(0);
+
+      const majority = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      expect(majority.stake.toNumber()).to.equal(LAMPORTS_PER_SOL);
+      expect(majority.disputesServed).to.equal(1);
+      expect(majority.slashCount).to.equal(0);
+
+      const registryAfter = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+      const slashed = registryAfter.forfeitedLamports.sub(registryBefore.forfeitedLamports).toNumber();
+      expect(slashed).to.equal(0.1 * LAMPORTS_PER_SOL);
+
+      try {
+        await settle(juror1);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("JurorAlreadySettled");
+      }
+
+      // Settled jurors are free to leave
+      await unregister(juror3);
+
+      // Admin moves the slashed stake out of the registry
+      const recipient = Keypair.generate();
+      await program.methods
+        .sweepJurorRegistry()
+        .accounts({
+          jurorRegistry: jurorRegistryPda,
+          admin: adminPda,
+          authority: admin.publicKey,
+          recipient: recipient.publicKey,
+        })
+        .signers([admin])
         .rpc();
 
-      // Execute verdict
-      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      expect(await provider.connection.getBalance(recipient.publicKey)).to.equal(registryAfter.forfeitedLamports.toNumber());
+      expect((await program.account.jurorRegistry.fetch(jurorRegistryPda)).forfeitedLamports.toNumber()).to.equal(0);
 
+      try {
+        await program.methods
+          .sweepJurorRegistry()
+          .accounts({
+            jurorRegistry: jurorRegistryPda,
+            admin: adminPda,
+            authority: admin.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientFunds");
+      }
+    });
+  });
+
+  describe('Juror Draw', () => {
+    beforeEach(async () => {
       await program.methods
-        .executeVerdict()
+        .initializeAdmin()
         .accounts({
-          dispute: disputeKeypair.publicKey,
-          offer: offerKeypair.publicKey,
-          escrowAccount: escrowPda,
-          buyer: buyer.publicKey,
-          seller: seller.publicKey,
           admin: adminPda,
           authority: admin.publicKey,
           systemProgram: SystemProgram.programId,
@@ -530,49 +3009,607 @@ describe('Comprehensive P2P Exchange Tests', () => {
         .signers([admin])
         .rpc();
 
-      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      await ensureDisputeSetup();
+      await ensureCooldownConfig();
+
+      // The buyer registers too, so the draw has a party to exclude
+      for (const juror of [juror1, juror2, juror3, buyer]) {
+        await program.methods
+          .registerJuror(new anchor.BN(LAMPORTS_PER_SOL))
+          .accounts({
+            jurorRegistry: jurorRegistryPda,
+            jurorStake: jurorStakePda(juror.publicKey),
+            juror: juror.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror])
+          .rpc();
+      }
+    });
+
+    const createTradeHistory = (user: Keypair) => program.methods
+      .createTradeHistory()
+      .accounts({
+        tradeHistory: tradeHistoryPda(user.publicKey),
+        user: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    // Runs a seller offer through to release with the given buyer
+    const completeTrade = async (offer: Keypair, tradeBuyer: Keypair) => {
+      await createSellerOffer(offer);
+      await program.methods
+        .listOffer(0)
+        .accounts({
+          offer: offer.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
+        .accounts({
+          offer: offer.publicKey,
+          buyer: tradeBuyer.publicKey,
+          escrowAccount: offerEscrowPda(offer.publicKey),
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(tradeBuyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([tradeBuyer])
+        .rpc();
+
+      await program.methods
+        .markFiatSent()
+        .accounts({
+          offer: offer.publicKey,
+          buyer: tradeBuyer.publicKey,
+        })
+        .signers([tradeBuyer])
+        .rpc();
+
+      await program.methods
+        .confirmFiatReceipt()
+        .accounts({
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .releaseSol()
+        .accounts({
+          offer: offer.publicKey,
+          seller: seller.publicKey,
+          buyer: tradeBuyer.publicKey,
+          escrowAccount: offerEscrowPda(offer.publicKey),
+          sellerTradeHistory: tradeHistoryPda(seller.publicKey),
+          buyerTradeHistory: tradeHistoryPda(tradeBuyer.publicKey),
+          buyerReputation: reputationPda(tradeBuyer.publicKey),
+          sellerReputation: reputationPda(seller.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+    };
+
+    // Seller opens a dispute against the buyer on a freshly accepted offer
+    const openDisputedTrade = async () => {
+      await createSellerOffer(offerKeypair);
+      await program.methods
+        .listOffer(0)
+        .accounts({
+          offer: offerKeypair.publicKey,
+          orderBookPage: orderBookPagePda,
+          seller: seller.publicKey,
+        })
+        .signers([seller])
+        .rpc();
+
+      await program.methods
+        .acceptOffer(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+          new anchor.BN(1000),
+          0
+        )
+        .accounts({
+          offer: offerKeypair.publicKey,
+          buyer: buyer.publicKey,
+          escrowAccount: escrowPda,
+          orderBookPage: orderBookPagePda,
+          buyerReputation: reputationPda(buyer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      await program.methods
+        .openDispute("Payment issue")
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          offer: offerKeypair.publicKey,
+          disputeEscrow: disputeEscrowPda(disputeKeypair.publicKey),
+          initiator: seller.publicKey,
+          respondent: buyer.publicKey,
+          disputeConfig: disputeConfigPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller, disputeKeypair])
+        .rpc();
+      await payDisputeFee(disputeKeypair.publicKey, buyer);
+    };
+
+    const sha256 = (...parts: Buffer[]) => createHash('sha256').update(Buffer.concat(parts)).digest();
+
+    // Replays the on-chain draw to find which juror stakes it examines, in order; a
+    // replacement draw refills the vacant seats from the replacement draw slot instead
+    const simulateDraw = async (dispute: PublicKey, replacement = false) => {
+      const disputeAccount = await program.account.dispute.fetch(dispute);
+      const registry = await program.account.jurorRegistry.fetch(jurorRegistryPda);
+      const drawSlot = replacement ? disputeAccount.replacementDrawSlot : disputeAccount.drawSlot;
+
+      // SlotHashes: u64 entry count, then (u64 slot, 32-byte hash) entries
+      const slotHashes = (await provider.connection.getAccountInfo(SYSVAR_SLOT_HASHES_PUBKEY)).data;
+      let slotHash: Buffer;
+      for (let offset = 8; offset + 40 <= slotHashes.length; offset += 40) {
+        if (new anchor.BN(slotHashes.subarray(offset, offset + 8), 'le').eq(drawSlot)) {
+          slotHash = slotHashes.subarray(offset + 8, offset + 40);
+          break;
+        }
+      }
+      expect(slotHash, "draw slot hash").to.not.be.undefined;
+
+      // Seated jurors of every round and jurors who vacated a seat are conflicted too
+      const excluded = [
+        disputeAccount.initiator,
+        disputeAccount.respondent,
+        ...disputeAccount.jurors,
+        ...disputeAccount.vacatedJurors,
+      ].map((key) => key.toString());
+      for (const party of [disputeAccount.initiator, disputeAccount.respondent]) {
+        const history = await program.account.tradeHistory.fetchNullable(tradeHistoryPda(party));
+        history?.recentCounterparties
+          .filter((key) => !key.equals(PublicKey.default))
+          .forEach((key) => excluded.push(key.toString()));
+      }
+
+      const drawSeed = replacement
+        ? sha256(slotHash, dispute.toBuffer(), drawSlot.toArrayLike(Buffer, 'le', 8))
+        : sha256(slotHash, dispute.toBuffer());
+      const seats = replacement
+        ? [...Array(disputeAccount.panelSize).keys()].filter((seat) => disputeAccount.vacantSeats & (1 << seat))
+        : [...Array(disputeAccount.panelSize).keys()];
+      const stakes: PublicKey[] = [];
+      const jurors: string[] = [];
+      for (let attempt = 0; attempt < 128 && jurors.length < seats.length; attempt++) {
+        const candidateHash = sha256(drawSeed, Buffer.from([attempt]));
+        const index = new anchor.BN(candidateHash.subarray(0, 8), 'le').modn(registry.jurors.length);
+        const candidate = registry.jurors[index].toString();
+        if (excluded.includes(candidate) || jurors.includes(candidate)) {
+          continue;
+        }
+
+        stakes.push(jurorStakePda(registry.jurors[index]));
+        const stake = await program.account.jurorStake.fetch(jurorStakePda(registry.jurors[index]));
+        if (stake.status !== 0 || stake.stake.lt(registry.minStake)) {
+          excluded.push(candidate);
+          continue;
+        }
+        jurors.push(candidate);
+      }
+
+      return { drawSeed, seats, stakes, jurors, excluded };
+    };
+
+    const waitForSlot = async (slot: anchor.BN) => {
+      while (new anchor.BN(await provider.connection.getSlot()).lte(slot)) {
+        await sleep(400);
+      }
+    };
+
+    // Waits until the cluster clock is past the given unix timestamp
+    const waitForTimestamp = async (timestamp: anchor.BN) => {
+      while (new anchor.BN(await provider.connection.getBlockTime(await provider.connection.getSlot())).lte(timestamp)) {
+        await sleep(400);
+      }
+    };
+
+    // Seats juror1-3 on the disputed trade
+    const assignPanel = () => program.methods
+      .assignJurors()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        jurorRegistry: jurorRegistryPda,
+        initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+        respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+        admin: adminPda,
+        authority: admin.publicKey,
+      })
+      .remainingAccounts(jurorStakeAccounts())
+      .signers([admin])
+      .rpc();
+
+    const recuse = (juror: Keypair) => program.methods
+      .recuseJuror()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        jurorStake: jurorStakePda(juror.publicKey),
+        vote: votePda(disputeKeypair.publicKey, juror.publicKey),
+        juror: juror.publicKey,
+      })
+      .signers([juror])
+      .rpc();
+
+    const replaceInactiveJuror = (juror: PublicKey) => program.methods
+      .replaceInactiveJuror()
+      .accounts({
+        dispute: disputeKeypair.publicKey,
+        jurorRegistry: jurorRegistryPda,
+        jurorStake: jurorStakePda(juror),
+        vote: votePda(disputeKeypair.publicKey, juror),
+        caller: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Waits for the replacement draw slot, then refills the vacant seats as the draw replays
+    const drawReplacements = async () => {
+      const disputeBefore = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      await waitForSlot(disputeBefore.replacementDrawSlot);
+
+      const draw = await simulateDraw(disputeKeypair.publicKey, true);
+      await program.methods
+        .drawReplacementJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .remainingAccounts(draw.stakes.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .rpc();
+      return draw;
+    };
+
+    it('Creates an empty trade history', async () => {
+      await createTradeHistory(buyer);
+
+      const history = await program.account.tradeHistory.fetch(tradeHistoryPda(buyer.publicKey));
+      expect(history.user.toString()).to.equal(buyer.publicKey.toString());
+      expect(history.nextIndex).to.equal(0);
+      expect(history.recentCounterparties.every((key) => key.equals(PublicKey.default))).to.be.true;
+    });
+
+    it('Records counterparties when a trade completes', async () => {
+      await createTradeHistory(seller);
+      await createTradeHistory(buyer);
+
+      await completeTrade(Keypair.generate(), buyer);
+
+      const sellerHistory = await program.account.tradeHistory.fetch(tradeHistoryPda(seller.publicKey));
+      const buyerHistory = await program.account.tradeHistory.fetch(tradeHistoryPda(buyer.publicKey));
+      expect(sellerHistory.recentCounterparties[0].toString()).to.equal(buyer.publicKey.toString());
+      expect(sellerHistory.nextIndex).to.equal(1);
+      expect(buyerHistory.recentCounterparties[0].toString()).to.equal(seller.publicKey.toString());
+    });
+
+    it('Rejects a draw before the draw slot', async () => {
+      await openDisputedTrade();
+
+      try {
+        await program.methods
+          .drawJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("DrawSlotNotReached");
+      }
+    });
+
+    it('Draws jurors from the slot hash, excluding parties and counterparties', async () => {
+      // juror1 once bought from the seller, so it is conflicted
+      await createTradeHistory(seller);
+      await createReputation(juror1);
+      await completeTrade(Keypair.generate(), juror1);
+
+      await openDisputedTrade();
+      const disputeBefore = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      await waitForSlot(disputeBefore.drawSlot);
+
+      const draw = await simulateDraw(disputeKeypair.publicKey);
+      expect(draw.excluded).to.include.members([
+        seller.publicKey.toString(),
+        buyer.publicKey.toString(),
+        juror1.publicKey.toString(),
+      ]);
+
+      await program.methods
+        .drawJurors()
+        .accounts({
+          dispute: disputeKeypair.publicKey,
+          jurorRegistry: jurorRegistryPda,
+          initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+          respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .remainingAccounts(draw.stakes.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .rpc();
+
       const disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
-      const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
+      const seated = disputeAccount.jurors.map((key) => key.toString());
+      expect(disputeAccount.status).to.equal(1); // JurorsAssigned status
+      expect(Buffer.from(disputeAccount.drawSeed).equals(draw.drawSeed)).to.be.true;
+      expect(seated).to.deep.equal(draw.jurors);
+      expect(seated).to.not.include(buyer.publicKey.toString());
+      expect(seated).to.not.include(juror1.publicKey.toString());
+
+      for (const juror of disputeAccount.jurors) {
+        const stake = await program.account.jurorStake.fetch(jurorStakePda(juror));
+        expect(stake.activeDisputes).to.be.greaterThan(0);
+      }
+    });
 
-      expect(disputeAccount.status).to.equal(5); // Resolved status
-      expect(offerAccount.status).to.equal(6); // Completed status
-      expect(buyerBalanceAfter).to.be.greaterThan(buyerBalanceBefore);
+    it('Lets a juror recuse and refills the seat from a future slot hash', async () => {
+      await openDisputedTrade();
+      await assignPanel();
+      await recuse(juror1);
+
+      let disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.vacantSeats).to.equal(1);
+      expect(disputeAccount.vacatedJurors.map((key) => key.toString())).to.deep.equal([juror1.publicKey.toString()]);
+      expect(disputeAccount.replacementDrawSlot.toNumber()).to.be.greaterThan(0);
+
+      // Recusal releases the juror without a penalty
+      const recusedStake = await program.account.jurorStake.fetch(jurorStakePda(juror1.publicKey));
+      expect(recusedStake.activeDisputes).to.equal(0);
+      expect(recusedStake.stake.toNumber()).to.equal(LAMPORTS_PER_SOL);
+
+      // The recused juror cannot commit for the seat anymore
+      await waitOut(disputeTimings.evidencePeriod);
+      try {
+        await program.methods
+          .commitVote(voteCommitment(disputeKeypair.publicKey, juror1.publicKey, 100, randomBytes(32)))
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            juror: juror1.publicKey,
+            vote: votePda(disputeKeypair.publicKey, juror1.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([juror1])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NotAJuror");
+      }
+
+      const draw = await drawReplacements();
+      expect(draw.seats).to.deep.equal([0]);
+
+      disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.vacantSeats).to.equal(0);
+      expect(disputeAccount.replacementDrawSlot.toNumber()).to.equal(0);
+      expect(disputeAccount.jurors[0].toString()).to.equal(draw.jurors[0]);
+      expect(draw.jurors[0]).to.not.be.oneOf([juror1, juror2, juror3].map((juror) => juror.publicKey.toString()));
+    });
+
+    it('Replaces a silent juror and never redraws a juror who vacated a seat', async () => {
+      await openDisputedTrade();
+      await assignPanel();
+
+      // juror2 still has time to commit
+      try {
+        await replaceInactiveJuror(juror2.publicKey);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("JurorNotInactive");
+      }
+
+      // juror3 recuses and its seat is refilled
+      await recuse(juror3);
+      await drawReplacements();
+
+      // Once juror2 sat through an inactivity window of the commit phase, anyone can replace it
+      let disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      await waitForTimestamp(disputeAccount.evidenceEndsAt.add(disputeTimings.inactivityWindow));
+      const signature = await replaceInactiveJuror(juror2.publicKey);
+
+      // The inactive juror is slashed for the missed vote
+      const slashed = (await transactionEvents(signature)).find((event) => event.name === 'jurorSlashed');
+      expect(slashed.data.reason).to.equal("inactive");
+      const inactiveStake = await program.account.jurorStake.fetch(jurorStakePda(juror2.publicKey));
+      expect(inactiveStake.missedVotes).to.equal(1);
+      expect(inactiveStake.activeDisputes).to.equal(0);
+      expect(inactiveStake.stake.toNumber()).to.equal(0.9 * LAMPORTS_PER_SOL);
+
+      // juror3 is no longer on the panel but stays excluded from the redraw
+      disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      expect(disputeAccount.jurors.map((key) => key.toString())).to.not.include(juror3.publicKey.toString());
+      expect(disputeAccount.vacatedJurors.map((key) => key.toString())).to.deep.equal([
+        juror3.publicKey.toString(),
+        juror2.publicKey.toString(),
+      ]);
+
+      const draw = await drawReplacements();
+      expect(draw.seats).to.deep.equal([1]);
+      expect(draw.excluded).to.include.members([juror2.publicKey.toString(), juror3.publicKey.toString()]);
+
+      disputeAccount = await program.account.dispute.fetch(disputeKeypair.publicKey);
+      const seated = disputeAccount.jurors.map((key) => key.toString());
+      expect(seated[1]).to.equal(draw.jurors[0]);
+      expect(seated).to.not.include(juror2.publicKey.toString());
+      expect(seated).to.not.include(juror3.publicKey.toString());
+
+      // No seat is left to refill
+      try {
+        await program.methods
+          .drawReplacementJurors()
+          .accounts({
+            dispute: disputeKeypair.publicKey,
+            jurorRegistry: jurorRegistryPda,
+            initiatorTradeHistory: tradeHistoryPda(seller.publicKey),
+            respondentTradeHistory: tradeHistoryPda(buyer.publicKey),
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NoVacantSeats");
+      }
     });
   });
 
   describe('Reputation System', () => {
-    it('Creates and updates reputation', async () => {
-      const [reputationPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reputation"), seller.publicKey.toBuffer()],
-        program.programId
-      );
-
-      // Create reputation
+    beforeEach(async () => {
       await program.methods
-        .createReputation()
+        .initializeAdmin()
         .accounts({
-          reputation: reputationPda,
-          user: seller.publicKey,
+          admin: adminPda,
+          authority: admin.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([seller])
+        .signers([admin])
         .rpc();
+    });
+
+    it('Creates and updates reputation', async () => {
+      // The seller's reputation was created before the test
+      const reputationAccountBefore = await program.account.reputation.fetch(reputationPda(seller.publicKey));
+      expect(reputationAccountBefore.successfulTrades).to.equal(0);
 
       // Update reputation
       await program.methods
-        .updateReputation(true, false, false) // Successful trade, no dispute
+        .updateReputation(true, false, false, new anchor.BN(LAMPORTS_PER_SOL)) // Successful 1 SOL trade, no dispute
         .accounts({
-          reputation: reputationPda,
+          reputation: reputationPda(seller.publicKey),
           user: seller.publicKey,
+          admin: adminPda,
+          authority: admin.publicKey,
         })
-        .signers([seller])
+        .signers([admin])
         .rpc();
 
-      const reputationAccount = await program.account.reputation.fetch(reputationPda);
+      const reputationAccount = await program.account.reputation.fetch(reputationPda(seller.publicKey));
       expect(reputationAccount.user.toString()).to.equal(seller.publicKey.toString());
       expect(reputationAccount.successfulTrades).to.equal(1);
       expect(reputationAccount.disputedTrades).to.equal(0);
-      expect(reputationAccount.rating).to.be.greaterThan(0);
+      expect(reputationAccount.totalVolume.toString()).to.equal(LAMPORTS_PER_SOL.toString());
+      expect(reputationAccount.weightedSuccesses.toNumber()).to.equal(1_000);
+      expect(reputationAccount.rating).to.be.greaterThan(50);
+    });
+
+    it('Rejects reputation updates without the admin', async () => {
+      try {
+        await program.methods
+          .updateReputation(true, false, false, new anchor.BN(LAMPORTS_PER_SOL))
+          .accounts({
+            reputation: reputationPda(seller.publicKey),
+            user: seller.publicKey,
+            admin: adminPda,
+            authority: seller.publicKey,
+          })
+          .signers([seller])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AdminRequired");
+      }
+
+      const reputationAccount = await program.account.reputation.fetch(reputationPda(seller.publicKey));
+      expect(reputationAccount.successfulTrades).to.equal(0);
+    });
+  });
+
+  describe('Offer and Dispute Cooldowns', () => {
+    beforeEach(async () => {
+      await program.methods
+        .initializeAdmin()
+        .accounts({
+          admin: adminPda,
+          authority: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await ensureCooldownConfig();
+    });
+
+    const updateCooldowns = (offerCreationCooldown: number, disputeOpeningCooldown: number) => program.methods
+      .updateCooldowns(new anchor.BN(offerCreationCooldown), new anchor.BN(disputeOpeningCooldown))
+      .accounts({
+        cooldownConfig: cooldownConfigPda,
+        admin: adminPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    it('Requires a reputation to create an offer', async () => {
+      const newSeller = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(newSeller.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+
+      try {
+        await createSellerOffer(offerKeypair, newSeller);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+    });
+
+    it('Rejects a second offer within the offer creation cooldown', async () => {
+      await updateCooldowns(60, 60);
+
+      try {
+        await createSellerOffer(offerKeypair);
+
+        try {
+          await createSellerOffer(Keypair.generate());
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("TooManyRequests");
+        }
+      } finally {
+        // Later tests create several offers in a row
+        await updateCooldowns(0, 0);
+      }
+
+      const reputationAccount = await program.account.reputation.fetch(reputationPda(seller.publicKey));
+      expect(reputationAccount.lastOfferCreated.toNumber()).to.be.greaterThan(0);
+    });
+
+    it('Rejects negative cooldowns', async () => {
+      try {
+        await updateCooldowns(-1, 0);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCooldown");
+      }
+
+      const config = await program.account.cooldownConfig.fetch(cooldownConfigPda);
+      expect(config.offerCreationCooldown.toNumber()).to.equal(0);
     });
   });
 
@@ -588,6 +3625,7 @@ describe('Comprehensive P2P Exchange Tests', () => {
         })
         .signers([admin])
         .rpc();
+      await ensureCooldownConfig();
 
       await program.methods
         .createOffer(
@@ -601,6 +3639,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
           escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
@@ -609,9 +3649,10 @@ describe('Comprehensive P2P Exchange Tests', () => {
       // Try to list offer with wrong signer
       try {
         await program.methods
-          .listOffer()
+          .listOffer(0)
           .accounts({
             offer: offerKeypair.publicKey,
+            orderBookPage: orderBookPagePda,
             seller: buyer.publicKey, // Wrong signer
           })
           .signers([buyer])
@@ -639,6 +3680,8 @@ describe('Comprehensive P2P Exchange Tests', () => {
             offer: offerKeypair.publicKey,
             seller: seller.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(seller.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([seller, offerKeypair])
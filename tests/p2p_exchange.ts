@@ -1,7 +1,7 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
 import { P2pExchange } from '../target/types/p2p_exchange';
-import { Keypair, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { Keypair, SystemProgram, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 import { expect } from 'chai';
 
 describe('p2p_exchange', () => {
@@ -13,13 +13,61 @@ describe('p2p_exchange', () => {
   let seller: Keypair;
   let buyer: Keypair;
   let offerKeypair: Keypair;
-  let escrowKeypair: Keypair;
+  let escrowPda: PublicKey;
+  let orderBookPagePda: PublicKey;
+
+  const reputationPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), user.toBuffer()],
+    program.programId
+  )[0];
+
+  // Created by the admin in the suites that initialize one, with cooldowns off
+  const [cooldownConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cooldown_config")],
+    program.programId
+  );
+
+  // Sellers need a reputation before creating offers
+  const createReputation = (user: Keypair) => program.methods
+    .createReputation()
+    .accounts({
+      reputation: reputationPda(user.publicKey),
+      user: user.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([user])
+    .rpc();
+
+  before(async () => {
+    // Listed offers go on the first USD order book page, which other suites may have created
+    const page = Buffer.alloc(4);
+    page.writeUInt32LE(0);
+    [orderBookPagePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("order_book"), Buffer.from("USD"), page],
+      program.programId
+    );
+
+    if (!(await provider.connection.getAccountInfo(orderBookPagePda))) {
+      await program.methods
+        .createOrderBookPage("USD", 0)
+        .accounts({
+          orderBookPage: orderBookPagePda,
+          previousPage: null,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
 
   beforeEach(() => {
     seller = Keypair.generate();
     buyer = Keypair.generate();
     offerKeypair = Keypair.generate();
-    escrowKeypair = Keypair.generate();
+    [escrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), offerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
   });
 
   it('Creates an offer', async () => {
@@ -27,6 +75,7 @@ describe('p2p_exchange', () => {
     await provider.connection.confirmTransaction(
       await provider.connection.requestAirdrop(seller.publicKey, 2 * LAMPORTS_PER_SOL)
     );
+    await createReputation(seller);
 
     const amount = LAMPORTS_PER_SOL; // 1 SOL
     const fiatAmount = 1000; // $1000
@@ -46,7 +95,9 @@ describe('p2p_exchange', () => {
         .accounts({
           offer: offerKeypair.publicKey,
           seller: seller.publicKey,
-          escrowAccount: escrowKeypair.publicKey,
+          escrowAccount: escrowPda,
+          reputation: reputationPda(seller.publicKey),
+          cooldownConfig: cooldownConfigPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([seller, offerKeypair])
@@ -71,6 +122,7 @@ describe('p2p_exchange', () => {
     await provider.connection.confirmTransaction(
       await provider.connection.requestAirdrop(seller.publicKey, 2 * LAMPORTS_PER_SOL)
     );
+    await createReputation(seller);
 
     const amount = LAMPORTS_PER_SOL;
     const fiatAmount = 1000;
@@ -89,17 +141,20 @@ describe('p2p_exchange', () => {
       .accounts({
         offer: offerKeypair.publicKey,
         seller: seller.publicKey,
-        escrowAccount: escrowKeypair.publicKey,
+        escrowAccount: escrowPda,
+        reputation: reputationPda(seller.publicKey),
+        cooldownConfig: cooldownConfigPda,
         systemProgram: SystemProgram.programId,
       })
       .signers([seller, offerKeypair])
       .rpc();
 
-    // Now list the offer
+    // Now list the offer on the first page
     await program.methods
-      .listOffer()
+      .listOffer(0)
       .accounts({
         offer: offerKeypair.publicKey,
+        orderBookPage: orderBookPagePda,
         seller: seller.publicKey,
       })
       .signers([seller])
@@ -108,6 +163,10 @@ describe('p2p_exchange', () => {
     // Fetch the offer account
     const offerAccount = await program.account.offer.fetch(offerKeypair.publicKey);
     expect(offerAccount.status).to.equal(1); // Listed status
+    expect(offerAccount.orderBookPage).to.equal(0);
+
+    const pageAccount = await program.account.orderBookPage.fetch(orderBookPagePda);
+    expect(pageAccount.offers.map((key) => key.toString())).to.include(offerKeypair.publicKey.toString());
   });
 
   it('Creates a reputation account', async () => {
@@ -137,6 +196,6 @@ describe('p2p_exchange', () => {
     const reputationAccount = await program.account.reputation.fetch(reputationPda);
     expect(reputationAccount.user.toString()).to.equal(userKeypair.publicKey.toString());
     expect(reputationAccount.successfulTrades).to.equal(0);
-    expect(reputationAccount.rating).to.equal(100);
+    expect(reputationAccount.rating).to.equal(50); // Neutral until trades build confidence
   });
 });
\ No newline at end of file
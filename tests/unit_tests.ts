@@ -26,6 +26,44 @@ describe('Unit Tests - P2P Exchange', () => {
 
   const program = anchor.workspace.P2pExchange as Program<P2pExchange>;
 
+  // First order book page of a currency, where these tests list their offers
+  const orderBookPagePda = (fiatCurrency: string) => {
+    const page = Buffer.alloc(4);
+    page.writeUInt32LE(0);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("order_book"), Buffer.from(fiatCurrency), page],
+      program.programId
+    )[0];
+  };
+
+  const reputationPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), user.toBuffer()],
+    program.programId
+  )[0];
+
+  const [cooldownConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cooldown_config")],
+    program.programId
+  );
+
+  before(async () => {
+    // Pages are shared by every suite on the validator, so only create missing ones
+    for (const fiatCurrency of ["USD"]) {
+      if (await provider.connection.getAccountInfo(orderBookPagePda(fiatCurrency))) {
+        continue;
+      }
+      await program.methods
+        .createOrderBookPage(fiatCurrency, 0)
+        .accounts({
+          orderBookPage: orderBookPagePda(fiatCurrency),
+          previousPage: null,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
+
   let admin: Keypair;
   let user1: Keypair;
   let user2: Keypair;
@@ -58,6 +96,19 @@ describe('Unit Tests - P2P Exchange', () => {
         await provider.connection.requestAirdrop(user3.publicKey, 5 * LAMPORTS_PER_SOL)
       ),
     ]);
+
+    // user1 and user2 trade with each other, which requires a reputation
+    for (const user of [user1, user2]) {
+      await program.methods
+        .createReputation()
+        .accounts({
+          reputation: reputationPda(user.publicKey),
+          user: user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
   });
 
   describe('Admin Module Tests', () => {
@@ -222,6 +273,31 @@ describe('Unit Tests - P2P Exchange', () => {
         .signers([admin])
         .rpc();
 
+      // The cooldown config is shared by every suite; cooldowns are off so
+      // tests can create several offers in a row
+      if (!(await provider.connection.getAccountInfo(cooldownConfigPda))) {
+        await program.methods
+          .initializeCooldownConfig()
+          .accounts({
+            cooldownConfig: cooldownConfigPda,
+            admin: adminPda,
+            authority: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        await program.methods
+          .updateCooldowns(new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            cooldownConfig: cooldownConfigPda,
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+      }
+
       offerKeypair = Keypair.generate();
       [escrowPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("escrow"), offerKeypair.publicKey.toBuffer()],
@@ -250,6 +326,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: offerKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, offerKeypair])
@@ -279,6 +357,8 @@ describe('Unit Tests - P2P Exchange', () => {
               offer: offerKeypair.publicKey,
               seller: user1.publicKey,
               escrowAccount: escrowPda,
+              reputation: reputationPda(user1.publicKey),
+              cooldownConfig: cooldownConfigPda,
               systemProgram: SystemProgram.programId,
             })
             .signers([user1, offerKeypair])
@@ -307,6 +387,8 @@ describe('Unit Tests - P2P Exchange', () => {
                 offer: Keypair.generate().publicKey,
                 seller: user1.publicKey,
                 escrowAccount: escrowPda,
+                reputation: reputationPda(user1.publicKey),
+                cooldownConfig: cooldownConfigPda,
                 systemProgram: SystemProgram.programId,
               })
               .signers([user1, Keypair.generate()])
@@ -335,6 +417,8 @@ describe('Unit Tests - P2P Exchange', () => {
               offer: offerKeypair.publicKey,
               seller: user1.publicKey,
               escrowAccount: escrowPda,
+              reputation: reputationPda(user1.publicKey),
+              cooldownConfig: cooldownConfigPda,
               systemProgram: SystemProgram.programId,
             })
             .signers([user1, offerKeypair])
@@ -362,6 +446,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: offerKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, offerKeypair])
@@ -392,6 +478,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: offerKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, offerKeypair])
@@ -400,9 +488,10 @@ describe('Unit Tests - P2P Exchange', () => {
 
       it('Should list offer successfully', async () => {
         await program.methods
-          .listOffer()
+          .listOffer(0)
           .accounts({
             offer: offerKeypair.publicKey,
+            orderBookPage: orderBookPagePda("USD"),
             seller: user1.publicKey,
           })
           .signers([user1])
@@ -416,9 +505,10 @@ describe('Unit Tests - P2P Exchange', () => {
       it('Should reject listing by non-seller', async () => {
         try {
           await program.methods
-            .listOffer()
+            .listOffer(0)
             .accounts({
               offer: offerKeypair.publicKey,
+              orderBookPage: orderBookPagePda("USD"),
               seller: user2.publicKey, // Not the seller
             })
             .signers([user2])
@@ -433,9 +523,10 @@ describe('Unit Tests - P2P Exchange', () => {
       it('Should reject listing already listed offer', async () => {
         // First listing
         await program.methods
-          .listOffer()
+          .listOffer(0)
           .accounts({
             offer: offerKeypair.publicKey,
+            orderBookPage: orderBookPagePda("USD"),
             seller: user1.publicKey,
           })
           .signers([user1])
@@ -444,9 +535,10 @@ describe('Unit Tests - P2P Exchange', () => {
         // Second listing should fail
         try {
           await program.methods
-            .listOffer()
+            .listOffer(0)
             .accounts({
               offer: offerKeypair.publicKey,
+              orderBookPage: orderBookPagePda("USD"),
               seller: user1.publicKey,
             })
             .signers([user1])
@@ -474,15 +566,18 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: offerKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, offerKeypair])
           .rpc();
 
         await program.methods
-          .listOffer()
+          .listOffer(0)
           .accounts({
             offer: offerKeypair.publicKey,
+            orderBookPage: orderBookPagePda("USD"),
             seller: user1.publicKey,
           })
           .signers([user1])
@@ -494,11 +589,18 @@ describe('Unit Tests - P2P Exchange', () => {
         const buyerBalanceBefore = await provider.connection.getBalance(user2.publicKey);
 
         await program.methods
-          .acceptOffer(new anchor.BN(securityBond))
+          .acceptOffer(
+            new anchor.BN(securityBond),
+            new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+            new anchor.BN(1000),
+            0
+          )
           .accounts({
             offer: offerKeypair.publicKey,
             buyer: user2.publicKey,
             escrowAccount: escrowPda,
+            orderBookPage: orderBookPagePda("USD"),
+            buyerReputation: reputationPda(user2.publicKey),
             systemProgram: SystemProgram.programId,
           })
           .signers([user2])
@@ -517,11 +619,18 @@ describe('Unit Tests - P2P Exchange', () => {
       it('Should reject acceptance by seller', async () => {
         try {
           await program.methods
-            .acceptOffer(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+            .acceptOffer(
+              new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+              new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+              new anchor.BN(1000),
+              0
+            )
             .accounts({
               offer: offerKeypair.publicKey,
               buyer: user1.publicKey, // Same as seller
               escrowAccount: escrowPda,
+              orderBookPage: orderBookPagePda("USD"),
+              buyerReputation: reputationPda(user1.publicKey), // Same as seller
               systemProgram: SystemProgram.programId,
             })
             .signers([user1])
@@ -553,6 +662,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: newOfferKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: newEscrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, newOfferKeypair])
@@ -560,11 +671,18 @@ describe('Unit Tests - P2P Exchange', () => {
 
         try {
           await program.methods
-            .acceptOffer(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+            .acceptOffer(
+              new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+              new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+              new anchor.BN(1000),
+              0
+            )
             .accounts({
               offer: newOfferKeypair.publicKey,
               buyer: user2.publicKey,
               escrowAccount: newEscrowPda,
+              orderBookPage: orderBookPagePda("USD"),
+              buyerReputation: reputationPda(user2.publicKey),
               systemProgram: SystemProgram.programId,
             })
             .signers([user2])
@@ -592,26 +710,36 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: offerKeypair.publicKey,
             seller: user1.publicKey,
             escrowAccount: escrowPda,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, offerKeypair])
           .rpc();
 
         await program.methods
-          .listOffer()
+          .listOffer(0)
           .accounts({
             offer: offerKeypair.publicKey,
+            orderBookPage: orderBookPagePda("USD"),
             seller: user1.publicKey,
           })
           .signers([user1])
           .rpc();
 
         await program.methods
-          .acceptOffer(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .acceptOffer(
+            new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+            new anchor.BN(1000),
+            0
+          )
           .accounts({
             offer: offerKeypair.publicKey,
             buyer: user2.publicKey,
             escrowAccount: escrowPda,
+            orderBookPage: orderBookPagePda("USD"),
+            buyerReputation: reputationPda(user2.publicKey),
             systemProgram: SystemProgram.programId,
           })
           .signers([user2])
@@ -719,26 +847,36 @@ describe('Unit Tests - P2P Exchange', () => {
               offer: newOfferKeypair.publicKey,
               seller: user1.publicKey,
               escrowAccount: newEscrowPda,
+              reputation: reputationPda(user1.publicKey),
+              cooldownConfig: cooldownConfigPda,
               systemProgram: SystemProgram.programId,
             })
             .signers([user1, newOfferKeypair])
             .rpc();
 
           await program.methods
-            .listOffer()
+            .listOffer(0)
             .accounts({
               offer: newOfferKeypair.publicKey,
+              orderBookPage: orderBookPagePda("USD"),
               seller: user1.publicKey,
             })
             .signers([user1])
             .rpc();
 
           await program.methods
-            .acceptOffer(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+            .acceptOffer(
+              new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+              new anchor.BN(LAMPORTS_PER_SOL), // Terms the buyer saw
+              new anchor.BN(1000),
+              0
+            )
             .accounts({
               offer: newOfferKeypair.publicKey,
               buyer: user2.publicKey,
               escrowAccount: newEscrowPda,
+              orderBookPage: orderBookPagePda("USD"),
+              buyerReputation: reputationPda(user2.publicKey),
               systemProgram: SystemProgram.programId,
             })
             .signers([user2])
@@ -765,6 +903,7 @@ describe('Unit Tests - P2P Exchange', () => {
 
   describe('Reputation Module Tests', () => {
     
+    // user3 does not trade, so it has no reputation yet
     let reputationPda: PublicKey;
 
     beforeEach(async () => {
@@ -780,7 +919,7 @@ describe('Unit Tests - P2P Exchange', () => {
         .rpc();
 
       [reputationPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reputation"), user1.publicKey.toBuffer()],
+        [Buffer.from("reputation"), user3.publicKey.toBuffer()],
         program.programId
       );
     });
@@ -792,19 +931,20 @@ describe('Unit Tests - P2P Exchange', () => {
           .createReputation()
           .accounts({
             reputation: reputationPda,
-            user: user1.publicKey,
+            user: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([user1])
+          .signers([user3])
           .rpc();
 
         const reputationAccount = await program.account.reputation.fetch(reputationPda);
-        expect(reputationAccount.user.toString()).to.equal(user1.publicKey.toString());
+        expect(reputationAccount.user.toString()).to.equal(user3.publicKey.toString());
         expect(reputationAccount.successfulTrades).to.equal(0);
         expect(reputationAccount.disputedTrades).to.equal(0);
         expect(reputationAccount.disputesWon).to.equal(0);
         expect(reputationAccount.disputesLost).to.equal(0);
-        expect(reputationAccount.rating).to.equal(100); // Starting rating
+        expect(reputationAccount.rating).to.equal(50); // Neutral until trades build confidence
+        expect(reputationAccount.totalVolume.toNumber()).to.equal(0);
         expect(reputationAccount.lastUpdated).to.be.greaterThan(0);
       });
 
@@ -814,10 +954,10 @@ describe('Unit Tests - P2P Exchange', () => {
           .createReputation()
           .accounts({
             reputation: reputationPda,
-            user: user1.publicKey,
+            user: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([user1])
+          .signers([user3])
           .rpc();
 
         // Second creation should fail
@@ -826,10 +966,10 @@ describe('Unit Tests - P2P Exchange', () => {
             .createReputation()
             .accounts({
               reputation: reputationPda,
-              user: user1.publicKey,
+              user: user3.publicKey,
               systemProgram: SystemProgram.programId,
             })
-            .signers([user1])
+            .signers([user3])
             .rpc();
           
           expect.fail("Should have failed on duplicate reputation creation");
@@ -846,18 +986,19 @@ describe('Unit Tests - P2P Exchange', () => {
           .createReputation()
           .accounts({
             reputation: reputationPda,
-            user: user1.publicKey,
+            user: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([user1])
+          .signers([user3])
           .rpc();
       });
 
       it('Should update reputation for successful trade', async () => {
         await program.methods
-          .updateReputation(true, false, false) // Successful trade
+          .updateReputation(true, false, false, new anchor.BN(20 * LAMPORTS_PER_SOL)) // Enough volume for full confidence
           .accounts({
             reputation: reputationPda,
+            user: user3.publicKey,
             admin: adminPda,
             authority: admin.publicKey,
           })
@@ -867,14 +1008,17 @@ describe('Unit Tests - P2P Exchange', () => {
         const reputationAccount = await program.account.reputation.fetch(reputationPda);
         expect(reputationAccount.successfulTrades).to.equal(1);
         expect(reputationAccount.disputedTrades).to.equal(0);
-        expect(reputationAccount.rating).to.equal(100); // Should remain high
+        expect(reputationAccount.weightedSuccesses.toNumber()).to.equal(20_000);
+        expect(reputationAccount.totalVolume.toString()).to.equal((20 * LAMPORTS_PER_SOL).toString());
+        expect(reputationAccount.rating).to.equal(100);
       });
 
       it('Should update reputation for dispute resolution', async () => {
         await program.methods
-          .updateReputation(false, true, true) // Dispute resolved, won
+          .updateReputation(false, true, true, new anchor.BN(LAMPORTS_PER_SOL)) // Dispute resolved, won
           .accounts({
             reputation: reputationPda,
+            user: user3.publicKey,
             admin: adminPda,
             authority: admin.publicKey,
           })
@@ -892,9 +1036,10 @@ describe('Unit Tests - P2P Exchange', () => {
         // Multiple successful trades
         for (let i = 0; i < 5; i++) {
           await program.methods
-            .updateReputation(true, false, false)
+            .updateReputation(true, false, false, new anchor.BN(LAMPORTS_PER_SOL))
             .accounts({
               reputation: reputationPda,
+              user: user3.publicKey,
               admin: adminPda,
               authority: admin.publicKey,
             })
@@ -904,9 +1049,10 @@ describe('Unit Tests - P2P Exchange', () => {
 
         // Add some disputes
         await program.methods
-          .updateReputation(false, true, false) // Lost dispute
+          .updateReputation(false, true, false, new anchor.BN(LAMPORTS_PER_SOL)) // Lost dispute
           .accounts({
             reputation: reputationPda,
+            user: user3.publicKey,
             admin: adminPda,
             authority: admin.publicKey,
           })
@@ -923,13 +1069,14 @@ describe('Unit Tests - P2P Exchange', () => {
       it('Should reject unauthorized reputation updates', async () => {
         try {
           await program.methods
-            .updateReputation(true, false, false)
+            .updateReputation(true, false, false, new anchor.BN(LAMPORTS_PER_SOL))
             .accounts({
               reputation: reputationPda,
+              user: user3.publicKey,
               admin: adminPda,
-              authority: user1.publicKey, // Not admin
+              authority: user3.publicKey, // Not admin
             })
-            .signers([user1])
+            .signers([user3])
             .rpc();
           
           expect.fail("Should have failed with unauthorized update");
@@ -938,13 +1085,67 @@ describe('Unit Tests - P2P Exchange', () => {
         }
       });
 
+      it('Should keep a neutral rating for dust trades', async () => {
+        await program.methods
+          .updateReputation(true, false, false, new anchor.BN(1_000)) // Far below one unit of weight
+          .accounts({
+            reputation: reputationPda,
+            user: user3.publicKey,
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        const reputationAccount = await program.account.reputation.fetch(reputationPda);
+        expect(reputationAccount.successfulTrades).to.equal(1);
+        expect(reputationAccount.weightedSuccesses.toNumber()).to.equal(0);
+        expect(reputationAccount.rating).to.equal(50);
+      });
+
+      it('Should cap the weight of a single trade', async () => {
+        await program.methods
+          .updateReputation(true, false, false, new anchor.BN(500 * LAMPORTS_PER_SOL))
+          .accounts({
+            reputation: reputationPda,
+            user: user3.publicKey,
+            admin: adminPda,
+            authority: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        const reputationAccount = await program.account.reputation.fetch(reputationPda);
+        expect(reputationAccount.weightedSuccesses.toNumber()).to.equal(100_000);
+        expect(reputationAccount.totalVolume.toString()).to.equal((500 * LAMPORTS_PER_SOL).toString());
+      });
+
+      it('Should not migrate a reputation that already has the current layout', async () => {
+        try {
+          await program.methods
+            .migrateReputation()
+            .accounts({
+              reputation: reputationPda,
+              user: user3.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([user3])
+            .rpc();
+
+          expect.fail("Should have failed on an already migrated reputation");
+        } catch (error) {
+          expect(error.message).to.include("ReputationAlreadyMigrated");
+        }
+      });
+
       it('Should protect against overflow in calculations', async () => {
         // Update many times to test overflow protection
         for (let i = 0; i < 100; i++) {
           await program.methods
-            .updateReputation(true, false, false)
+            .updateReputation(true, false, false, new anchor.BN(LAMPORTS_PER_SOL))
             .accounts({
               reputation: reputationPda,
+              user: user3.publicKey,
               admin: adminPda,
               authority: admin.publicKey,
             })
@@ -979,6 +1180,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: Keypair.generate().publicKey,
             seller: user1.publicKey,
             escrowAccount: PublicKey.default,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, Keypair.generate()])
@@ -1001,6 +1204,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: Keypair.generate().publicKey,
             seller: user1.publicKey,
             escrowAccount: PublicKey.default,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, Keypair.generate()])
@@ -1023,6 +1228,8 @@ describe('Unit Tests - P2P Exchange', () => {
             offer: Keypair.generate().publicKey,
             seller: user1.publicKey,
             escrowAccount: PublicKey.default,
+            reputation: reputationPda(user1.publicKey),
+            cooldownConfig: cooldownConfigPda,
             systemProgram: SystemProgram.programId,
           })
           .signers([user1, Keypair.generate()])